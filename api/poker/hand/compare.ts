@@ -9,7 +9,11 @@ import {
     parseHole,
     parseBoard,
 } from "@common/interfaces";
-import { hand } from "../../../server/src/integrations/hand";
+import {
+    bestHandRust,
+    describeHandRust,
+    compareHandRanks,
+} from "../../../server/src/integrations/hand/equity";
 import { requireAuth } from "../../utils/auth";
 import { handleCors } from "../../utils/cors";
 import { strictRateLimiter } from "../../utils/rateLimit";
@@ -47,7 +51,12 @@ export default async function handler(
         // Check authentication
         requireAuth(req);
 
-        const { hole1, hole2, board = "" }: CompareHandsRequest = req.body;
+        const {
+            hole1,
+            hole2,
+            board = "",
+            locale,
+        }: CompareHandsRequest = req.body;
 
         if (!hole1 || !hole2) {
             const errorResponse: ApiErrorResponse = {
@@ -65,17 +74,19 @@ export default async function handler(
         const allCards1 = [...holeCards1.cards, ...boardCards.cards];
         const allCards2 = [...holeCards2.cards, ...boardCards.cards];
 
-        if (allCards1.length !== 7 || allCards2.length !== 7) {
+        if (boardCards.cards.length < 3 || boardCards.cards.length > 5) {
             const errorResponse: ApiErrorResponse = {
-                error: "Both players need exactly 7 cards (2 hole + 5 board)",
+                error: "Both players need 5 to 7 cards (2 hole + 3-5 board)",
             };
             res.status(400).json(errorResponse);
             return;
         }
 
-        const handRank1 = hand.evaluate7(allCards1);
-        const handRank2 = hand.evaluate7(allCards2);
-        const comparison = hand.compareRanks(handRank1, handRank2);
+        const best1 = await bestHandRust(allCards1);
+        const best2 = await bestHandRust(allCards2);
+        const comparison = compareHandRanks(best1.handRank, best2.handRank);
+        const description1 = await describeHandRust(best1.handRank, locale);
+        const description2 = await describeHandRust(best2.handRank, locale);
 
         const result =
             comparison > 0
@@ -87,11 +98,15 @@ export default async function handler(
         const response: CompareHandsResponse = {
             hand1: {
                 hole: holeCards1.cards,
-                rank: handRank1,
+                rank: best1.handRank,
+                bestCards: best1.bestCards,
+                description: description1,
             },
             hand2: {
                 hole: holeCards2.cards,
-                rank: handRank2,
+                rank: best2.handRank,
+                bestCards: best2.bestCards,
+                description: description2,
             },
             comparison: {
                 result: result,
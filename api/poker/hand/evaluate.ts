@@ -9,7 +9,10 @@ import {
     parseHole,
     parseBoard,
 } from "@common/interfaces";
-import { hand } from "../../../server/src/integrations/hand";
+import {
+    bestHandRust,
+    describeHandRust,
+} from "../../../server/src/integrations/hand/equity";
 import { requireAuth } from "../../utils/auth";
 import { handleCors } from "../../utils/cors";
 import { strictRateLimiter } from "../../utils/rateLimit";
@@ -47,7 +50,7 @@ export default async function handler(
         // Check authentication
         requireAuth(req);
 
-        const { hole, board = "" }: EvaluateHandRequest = req.body;
+        const { hole, board = "", locale }: EvaluateHandRequest = req.body;
 
         if (!hole) {
             const errorResponse: ApiErrorResponse = {
@@ -63,41 +66,22 @@ export default async function handler(
         // Combine hole and board cards
         const allCards = [...holeCards.cards, ...boardCards.cards];
 
-        if (allCards.length < 2) {
-            const errorResponse: ApiErrorResponse = {
-                error: "Need at least 2 cards (hole cards) to evaluate",
-            };
-            res.status(400).json(errorResponse);
-            return;
-        }
-
-        if (allCards.length > 7) {
+        if (allCards.length < 5 || allCards.length > 7) {
             const errorResponse: ApiErrorResponse = {
-                error: "Cannot evaluate more than 7 cards",
+                error: `Need 5 to 7 cards (2 hole + 3-5 board) to evaluate. You provided ${allCards.length} cards (2 hole + ${boardCards.cards.length} board).`,
             };
             res.status(400).json(errorResponse);
             return;
         }
 
-        // evaluate7 requires exactly 7 cards
-        let handRank;
-        if (allCards.length === 7) {
-            handRank = hand.evaluate7(allCards);
-        } else if (allCards.length === 5) {
-            res.status(400).json({
-                error: "Please provide exactly 7 cards (2 hole + 5 board) for accurate evaluation",
-            });
-            return;
-        } else {
-            const errorResponse: ApiErrorResponse = {
-                error: `Currently requires exactly 7 cards for evaluation. You provided ${allCards.length} cards (2 hole + ${boardCards.cards.length} board).`,
-            };
-            res.status(400).json(errorResponse);
-            return;
-        }
+        // Throws for invalid or duplicate cards
+        const { handRank, bestCards } = await bestHandRust(allCards);
+        const description = await describeHandRust(handRank, locale);
 
         const response: EvaluateHandResponse = {
             handRank,
+            bestCards,
+            description,
             hole: holeCards.cards,
             board: boardCards.cards,
         };
@@ -9,10 +9,13 @@ import { HandRank, EquityOptions, Card } from "./index";
 export interface EvaluateHandRequest {
     hole: string; // e.g., "14h 14d"
     board?: string; // e.g., "12h 11h 10h" (optional, can be empty string)
+    locale?: string; // language of `description`, e.g. "en" (default)
 }
 
 export interface EvaluateHandResponse {
     handRank: HandRank;
+    bestCards: Card[]; // the five cards that make the hand, in tiebreak order
+    description: string; // e.g. "Full house, Aces full of Kings"
     hole: Card[];
     board: Card[];
 }
@@ -22,16 +25,21 @@ export interface CompareHandsRequest {
     hole1: string;
     hole2: string;
     board?: string;
+    locale?: string;
 }
 
 export interface CompareHandsResponse {
     hand1: {
         hole: Card[];
         rank: HandRank;
+        bestCards: Card[]; // the five cards that make the hand, in tiebreak order
+        description: string; // e.g. "Pair of Queens, Ace kicker"
     };
     hand2: {
         hole: Card[];
         rank: HandRank;
+        bestCards: Card[];
+        description: string;
     };
     comparison: {
         result: "hand1_wins" | "hand2_wins" | "tie";
@@ -1,10 +1,27 @@
 export interface EquityResult {
     win: number[]; // per player, fraction in [0, 1]
-    tie: number[]; // per player, fraction in [0, 1]
+    tie: number[]; // per player, expected pot share from split pots (a 3-way split counts 1/3)
     lose: number[]; // per player, fraction in [0, 1]
     samples: number; // number of board completions evaluated
+    tie_probability?: number[]; // per player, probability of splitting the pot at all
+    chops?: number[][]; // chops[player][n]: probability of splitting the pot n ways (n >= 2)
+    exact?: boolean; // random opponents: whether the result was enumerated exactly or sampled
+    std_error?: number[]; // per player, standard error of equity (sampling modes only)
+    ci95?: [number, number][]; // per player, 95% confidence interval of equity (sampling modes only)
+    stop_reason?: "precision" | "time" | "samples"; // adaptive mode: limit that stopped sampling
+    counts?: BoardCounts; // exact, sampled and preflop table results: integer counts behind the fractions
+}
+
+export interface BoardCounts {
+    wins: number[]; // per player, boards won outright
+    ties: number[][]; // ties[player][n]: boards the player split n ways (tie = sum(ties[player][n] / n) / samples)
 }
 
 export interface EquityOptions {
-    mode?: "rust"; // Only Rust WASM mode is supported
+    mode?: "rust" | "monte_carlo" | "adaptive"; // Exact enumeration (default), seeded sampling, or sampling to a precision/time target
+    samples?: number; // monte_carlo: number of sampled boards; adaptive: sample cap
+    seed?: number; // monte_carlo/adaptive: seed for reproducible results
+    targetPrecision?: number; // adaptive: max 95% confidence half-width, e.g. 0.001 for +/-0.1%
+    timeBudgetMs?: number; // adaptive: time budget in milliseconds (capped by the server)
+    randomOpponents?: number; // extra seats with unknown hands, appended after the known players
 }
\ No newline at end of file
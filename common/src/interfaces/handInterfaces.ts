@@ -99,3 +99,18 @@ export interface HandRank {
     category: HandCategory;
     tiebreak: CardRank[];
 }
+
+/**
+ * Words and templates for hand descriptions in one locale
+ * (`MessageCatalog` in the Rust engine's describe.rs)
+ * Category templates name tiebreak ranks by position: {0} is the first rank's
+ * name, {0s} its plural; with_kicker uses {hand} and {kicker}, wheel uses {hand}
+ */
+export interface HandMessageCatalog {
+    locale: string; // e.g. "de" or "de-AT"
+    ranks: string[]; // 13 names, Two to Ace
+    rank_plurals: string[]; // 13 plurals, Twos to Aces
+    categories: string[]; // 10 templates, high card to royal flush
+    with_kicker: string; // e.g. "{hand}, {kicker} kicker"
+    wheel: string; // e.g. "{hand} (wheel)"
+}
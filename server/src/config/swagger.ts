@@ -154,6 +154,12 @@ const options: swaggerJsdoc.Options = {
                             example: "12h 11h 10h 9h 8h",
                             default: "",
                         },
+                        locale: {
+                            type: "string",
+                            description:
+                                "Language of the hand description (optional, falls back to English)",
+                            example: "en",
+                        },
                     },
                 },
                 EvaluateHandResponse: {
@@ -162,6 +168,10 @@ const options: swaggerJsdoc.Options = {
                         handRank: {
                             $ref: "#/components/schemas/HandRank",
                         },
+                        description: {
+                            type: "string",
+                            example: "Straight flush, Queen high",
+                        },
                         hole: {
                             type: "array",
                             items: {
@@ -175,7 +185,7 @@ const options: swaggerJsdoc.Options = {
                             },
                         },
                     },
-                    required: ["handRank", "hole", "board"],
+                    required: ["handRank", "description", "hole", "board"],
                 },
                 CompareHandsRequest: {
                     type: "object",
@@ -198,6 +208,12 @@ const options: swaggerJsdoc.Options = {
                             example: "12h 11h 10h 9h 8h",
                             default: "",
                         },
+                        locale: {
+                            type: "string",
+                            description:
+                                "Language of the hand description (optional, falls back to English)",
+                            example: "en",
+                        },
                     },
                 },
                 CompareHandsResponse: {
@@ -215,6 +231,9 @@ const options: swaggerJsdoc.Options = {
                                 rank: {
                                     $ref: "#/components/schemas/HandRank",
                                 },
+                                description: {
+                                    type: "string",
+                                },
                             },
                         },
                         hand2: {
@@ -229,6 +248,9 @@ const options: swaggerJsdoc.Options = {
                                 rank: {
                                     $ref: "#/components/schemas/HandRank",
                                 },
+                                description: {
+                                    type: "string",
+                                },
                             },
                         },
                         comparison: {
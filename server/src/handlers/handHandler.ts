@@ -2,7 +2,6 @@ import { Request, Response } from "express";
 import {
     Card,
     Hole,
-    HandRank,
     parseCard,
     parseHole,
     parseBoard,
@@ -14,23 +13,26 @@ import {
     CalculateEquityResponse,
     ApiErrorResponse,
 } from "@common/interfaces";
-import { hand } from "../integrations/hand";
-import { computeEquity } from "../integrations/hand/equity";
+import {
+    computeEquity,
+    bestHandRust,
+    describeHandRust,
+    compareHandRanks,
+} from "../integrations/hand/equity";
 
 class HandHandler {
     /**
      * Evaluate a hand (hole cards + board)
      * POST /poker/hand/evaluate
      *
-     * Body: { hole: "14h 14d", board?: "12h 11h 10h 9h 8h" }
+     * Body: { hole: "14h 14d", board?: "12h 11h 10h 9h 8h", locale?: "en" }
      *
-     * Note: For best results, provide 7 cards total (2 hole + 5 board).
-     * If fewer cards are provided, the function will evaluate the best hand
-     * from the available cards, but results may not be accurate for incomplete boards.
+     * Evaluates the best five-card hand from the hole and a flop, turn or river
+     * board (5-7 cards) with the Rust evaluator, and describes it in words.
      */
     evaluateHand = async (req: Request, res: Response) => {
         try {
-            const { hole, board = "" }: EvaluateHandRequest = req.body;
+            const { hole, board = "", locale }: EvaluateHandRequest = req.body;
 
             if (!hole) {
                 const errorResponse: ApiErrorResponse = {
@@ -45,42 +47,21 @@ class HandHandler {
             // Combine hole and board cards
             const allCards = [...holeCards.cards, ...boardCards.cards];
 
-            if (allCards.length < 2) {
+            if (allCards.length < 5 || allCards.length > 7) {
                 const errorResponse: ApiErrorResponse = {
-                    error: "Need at least 2 cards (hole cards) to evaluate",
+                    error: `Need 5 to 7 cards (2 hole + 3-5 board) to evaluate. You provided ${allCards.length} cards (2 hole + ${boardCards.cards.length} board).`,
                 };
                 return res.status(400).json(errorResponse);
             }
 
-            if (allCards.length > 7) {
-                const errorResponse: ApiErrorResponse = {
-                    error: "Cannot evaluate more than 7 cards",
-                };
-                return res.status(400).json(errorResponse);
-            }
-
-            // evaluate7 requires exactly 7 cards
-            // If we have fewer, we need to handle it differently
-            let handRank: HandRank;
-            if (allCards.length === 7) {
-                handRank = hand.evaluate7(allCards);
-            } else if (allCards.length === 5) {
-                // For 5 cards, we can evaluate directly by creating a dummy 7-card hand
-                // But actually, we should just evaluate the 5-card hand
-                // Since evaluate7 requires 7 cards, we'll pad with dummy cards that won't affect the result
-                // Actually, this is complex. Let's just require 7 cards for now.
-                return res.status(400).json({
-                    error: "Please provide exactly 7 cards (2 hole + 5 board) for accurate evaluation",
-                });
-            } else {
-                const errorResponse: ApiErrorResponse = {
-                    error: `Currently requires exactly 7 cards for evaluation. You provided ${allCards.length} cards (2 hole + ${boardCards.cards.length} board).`,
-                };
-                return res.status(400).json(errorResponse);
-            }
+            // Throws for invalid or duplicate cards
+            const { handRank, bestCards } = await bestHandRust(allCards);
+            const description = await describeHandRust(handRank, locale);
 
             const response: EvaluateHandResponse = {
                 handRank,
+                bestCards,
+                description,
                 hole: holeCards.cards,
                 board: boardCards.cards,
             };
@@ -99,7 +80,12 @@ class HandHandler {
      */
     compareHands = async (req: Request, res: Response) => {
         try {
-            const { hole1, hole2, board = "" }: CompareHandsRequest = req.body;
+            const {
+                hole1,
+                hole2,
+                board = "",
+                locale,
+            }: CompareHandsRequest = req.body;
 
             if (!hole1 || !hole2) {
                 const errorResponse: ApiErrorResponse = {
@@ -116,16 +102,18 @@ class HandHandler {
             const allCards1 = [...holeCards1.cards, ...boardCards.cards];
             const allCards2 = [...holeCards2.cards, ...boardCards.cards];
 
-            if (allCards1.length !== 7 || allCards2.length !== 7) {
+            if (boardCards.cards.length < 3 || boardCards.cards.length > 5) {
                 const errorResponse: ApiErrorResponse = {
-                    error: "Both players need exactly 7 cards (2 hole + 5 board)",
+                    error: "Both players need 5 to 7 cards (2 hole + 3-5 board)",
                 };
                 return res.status(400).json(errorResponse);
             }
 
-            const handRank1 = hand.evaluate7(allCards1);
-            const handRank2 = hand.evaluate7(allCards2);
-            const comparison = hand.compareRanks(handRank1, handRank2);
+            const best1 = await bestHandRust(allCards1);
+            const best2 = await bestHandRust(allCards2);
+            const comparison = compareHandRanks(best1.handRank, best2.handRank);
+            const description1 = await describeHandRust(best1.handRank, locale);
+            const description2 = await describeHandRust(best2.handRank, locale);
 
             const result =
                 comparison > 0
@@ -137,11 +125,15 @@ class HandHandler {
             const response: CompareHandsResponse = {
                 hand1: {
                     hole: holeCards1.cards,
-                    rank: handRank1,
+                    rank: best1.handRank,
+                    bestCards: best1.bestCards,
+                    description: description1,
                 },
                 hand2: {
                     hole: holeCards2.cards,
-                    rank: handRank2,
+                    rank: best2.handRank,
+                    bestCards: best2.bestCards,
+                    description: description2,
                 },
                 comparison: {
                     result: result,
@@ -162,6 +154,14 @@ class HandHandler {
      * POST /poker/equity/calculate
      */
     calculateEquity = async (req: Request, res: Response) => {
+        // Stop enumerating if the client disconnects before the response is sent
+        const abortController = new AbortController();
+        res.on("close", () => {
+            if (!res.writableFinished) {
+                abortController.abort();
+            }
+        });
+
         try {
             const {
                 players,
@@ -191,7 +191,8 @@ class HandHandler {
                 parsedPlayers,
                 parsedBoard,
                 options,
-                parsedDead
+                parsedDead,
+                abortController.signal
             );
 
             const response: CalculateEquityResponse = {
@@ -203,6 +204,9 @@ class HandHandler {
             };
             res.status(200).json(response);
         } catch (error: any) {
+            if (abortController.signal.aborted) {
+                return; // Client is gone, nothing to respond to
+            }
             const errorResponse: ApiErrorResponse = {
                 error: error.message || "Failed to calculate equity",
             };
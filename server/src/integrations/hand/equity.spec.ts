@@ -129,6 +129,9 @@ describe("computeEquity", () => {
             expect(result.tie[0]).toBeCloseTo(1 / 3);
             expect(result.tie[1]).toBeCloseTo(1 / 3);
             expect(result.tie[2]).toBeCloseTo(1 / 3);
+            // Every player chops, always three ways
+            expect(result.tie_probability).toEqual([1, 1, 1]);
+            expect(result.chops?.[0]).toEqual([0, 0, 0, 1]);
         });
     });
 
@@ -1,26 +1,25 @@
-import { Card, Hole, Board, EquityResult } from "@common/interfaces";
+import {
+    Card,
+    Hole,
+    Board,
+    EquityResult,
+    CalculateOutsResponse,
+    OutCard,
+    HandRank,
+    HandMessageCatalog,
+} from "@common/interfaces";
+import type * as WasmEquity from "../../../wasm-equity/pkg/wasm_equity";
 import * as path from "path";
 import * as fs from "fs";
 
-// Type definitions for WASM module
-interface WasmModule {
-    calculate_preflop_equity(
-        player_ranks: Uint8Array,
-        player_suits: Uint8Array,
-        deck_ranks: Uint8Array,
-        deck_suits: Uint8Array,
-        num_players: number,
-        missing: number
-    ): string;
-    compute_turn_outs(
-        hero_ranks: Uint8Array,
-        hero_suits: Uint8Array,
-        villain_ranks: Uint8Array,
-        villain_suits: Uint8Array,
-        board_ranks: Uint8Array,
-        board_suits: Uint8Array
-    ): string;
-}
+// The WASM exports, typed by the declarations wasm-bindgen and tsify generate from
+// the Rust code into pkg/wasm_equity.d.ts (`npm run build:wasm`), so signatures and
+// result types follow the Rust structs. Results are plain objects and invalid
+// input throws an Error
+type WasmModule = typeof WasmEquity;
+
+// Default number of sampled boards for Monte Carlo mode
+const DEFAULT_MONTE_CARLO_SAMPLES = 100000;
 
 let wasmModule: WasmModule | null = null;
 let wasmModulePromise: Promise<WasmModule> | null = null;
@@ -40,7 +39,7 @@ async function initWasmModule(): Promise<WasmModule> {
     wasmModulePromise = (async (): Promise<WasmModule> => {
         try {
             // Try to load WASM module from the wasm-equity/pkg directory
-            // This will be built using wasm-pack build --target nodejs
+            // This will be built using wasm-pack build --target nodejs -- --features wasm
             // From dist/server/src/integrations/hand/, go up 3 levels to dist/server/
             // Path: dist/server/src/integrations/hand/ -> ../../../ -> dist/server/
             const wasmPath = path.resolve(
@@ -52,7 +51,7 @@ async function initWasmModule(): Promise<WasmModule> {
             // Check if file exists first
             if (!fs.existsSync(wasmPath)) {
                 throw new Error(
-                    `WASM module not found at ${wasmPath}. Please run 'wasm-pack build --target nodejs' in the wasm-equity directory.`
+                    `WASM module not found at ${wasmPath}. Please run 'wasm-pack build --target nodejs -- --features wasm' in the wasm-equity directory.`
                 );
             }
 
@@ -67,11 +66,13 @@ async function initWasmModule(): Promise<WasmModule> {
                     "WASM module initialization returned null/undefined"
                 );
             }
+            loadPreflopTable(wasmInstance);
+            selectEvaluatorBackend(wasmInstance);
             wasmModule = wasmInstance;
             return wasmInstance;
         } catch (error: any) {
             throw new Error(
-                `Failed to load WASM equity module: ${error.message || error}. Make sure to run 'wasm-pack build --target nodejs' in the wasm-equity directory.`
+                `Failed to load WASM equity module: ${error.message || error}. Make sure to run 'wasm-pack build --target nodejs -- --features wasm' in the wasm-equity directory.`
             );
         }
     })();
@@ -79,6 +80,44 @@ async function initWasmModule(): Promise<WasmModule> {
     return wasmModulePromise;
 }
 
+/**
+ * Load the heads-up preflop table (written by `wasm-equity preflop-table`) if present,
+ * so heads-up preflop requests are answered by lookup instead of enumeration
+ */
+function loadPreflopTable(wasm: WasmModule): void {
+    const tablePath = path.resolve(
+        __dirname,
+        "../../../wasm-equity/preflop_table.bin"
+    );
+    if (!fs.existsSync(tablePath)) {
+        return;
+    }
+
+    try {
+        wasm.load_preflop_table(new Uint8Array(fs.readFileSync(tablePath)));
+    } catch (error: any) {
+        console.warn(
+            `Ignoring preflop table at ${tablePath}: ${error.message || error}`
+        );
+    }
+}
+
+/**
+ * Select the Rust hand evaluator from EQUITY_EVALUATOR ("direct" or "table")
+ */
+function selectEvaluatorBackend(wasm: WasmModule): void {
+    const backend = process.env.EQUITY_EVALUATOR;
+    if (!backend) {
+        return;
+    }
+
+    try {
+        wasm.set_evaluator_backend(backend);
+    } catch (error: any) {
+        console.warn(`Ignoring EQUITY_EVALUATOR: ${error.message || error}`);
+    }
+}
+
 /**
  * Convert suit string to number: "c"=0, "d"=1, "h"=2, "s"=3
  */
@@ -98,24 +137,25 @@ function suitToNumber(suit: string): number {
 }
 
 /**
- * Calculate preflop equity using Rust WASM
- * This is optimized for preflop scenarios (board.length === 0)
+ * Flattened rank/suit arrays passed to the Rust equity exports
  */
-export async function calculateEquityRust(
+interface EquityCardArrays {
+    playerRanks: Uint8Array;
+    playerSuits: Uint8Array;
+    boardRanks: Uint8Array;
+    boardSuits: Uint8Array;
+    deadRanks: Uint8Array;
+    deadSuits: Uint8Array;
+}
+
+/**
+ * Convert players, board and dead cards into flattened rank/suit arrays
+ */
+function prepareEquityArrays(
     players: readonly Hole[],
     board: Board,
-    remainingDeck: Card[]
-): Promise<EquityResult> {
-    // Only support preflop (empty board) for now
-    if (board.cards.length !== 0) {
-        throw new Error(
-            "Rust equity calculation currently only supports preflop (empty board)"
-        );
-    }
-
-    const numPlayers = players.length;
-    const missing = 5; // Always 5 cards for preflop
-
+    dead: readonly Card[]
+): EquityCardArrays {
     // Prepare player data (ranks and suits separately)
     const playerRanks: number[] = [];
     const playerSuits: number[] = [];
@@ -127,32 +167,215 @@ export async function calculateEquityRust(
         }
     }
 
-    // Prepare deck data
-    const deckRanks: number[] = [];
-    const deckSuits: number[] = [];
+    return {
+        playerRanks: new Uint8Array(playerRanks),
+        playerSuits: new Uint8Array(playerSuits),
+        boardRanks: new Uint8Array(board.cards.map((c) => c.rank)),
+        boardSuits: new Uint8Array(
+            board.cards.map((c) => suitToNumber(c.suit))
+        ),
+        deadRanks: new Uint8Array(dead.map((c) => c.rank)),
+        deadSuits: new Uint8Array(dead.map((c) => suitToNumber(c.suit))),
+    };
+}
+
+/**
+ * Calculate equity using Rust WASM exact enumeration
+ * Works on every street: preflop (0), flop (3), turn (4) and river (5) boards
+ * The remaining deck is built (and duplicate/invalid cards rejected) in Rust
+ */
+export async function calculateEquityRust(
+    players: readonly Hole[],
+    board: Board,
+    dead: readonly Card[] = []
+): Promise<EquityResult> {
+    const arrays = prepareEquityArrays(players, board, dead);
 
-    for (const card of remainingDeck) {
-        deckRanks.push(card.rank);
-        deckSuits.push(suitToNumber(card.suit));
+    // Initialize WASM module
+    const wasm = await initWasmModule();
+
+    // Call WASM function (throws on invalid input)
+    return wasm.calculate_equity(
+        arrays.playerRanks,
+        arrays.playerSuits,
+        arrays.boardRanks,
+        arrays.boardSuits,
+        arrays.deadRanks,
+        arrays.deadSuits,
+        players.length
+    );
+}
+
+// Boards enumerated per job step before yielding to the event loop (~5-15ms)
+const DEFAULT_JOB_STEP_BOARDS = 50000;
+
+/**
+ * Options for a stepped exact equity calculation
+ */
+export interface EquityJobOptions {
+    signal?: AbortSignal; // aborts the calculation between steps
+    onProgress?: (progress: number) => void; // fraction of boards enumerated (0-1)
+    stepBoards?: number; // boards per step
+}
+
+/**
+ * Calculate equity using Rust WASM exact enumeration, in steps
+ * Same result as calculateEquityRust, but the enumeration yields to the event loop
+ * between steps so other requests are served, and it stops early (rejecting with
+ * "Equity calculation cancelled") once `signal` is aborted
+ */
+export async function calculateEquityRustJob(
+    players: readonly Hole[],
+    board: Board,
+    dead: readonly Card[] = [],
+    options: EquityJobOptions = {}
+): Promise<EquityResult> {
+    const arrays = prepareEquityArrays(players, board, dead);
+    const stepBoards = options.stepBoards ?? DEFAULT_JOB_STEP_BOARDS;
+
+    // Initialize WASM module
+    const wasm = await initWasmModule();
+
+    const job = new wasm.EquityJob(
+        arrays.playerRanks,
+        arrays.playerSuits,
+        arrays.boardRanks,
+        arrays.boardSuits,
+        arrays.deadRanks,
+        arrays.deadSuits,
+        players.length
+    );
+    try {
+        while (!job.is_finished()) {
+            if (options.signal?.aborted) {
+                job.cancel();
+                throw new Error("Equity calculation cancelled");
+            }
+
+            const status = job.step(stepBoards);
+            options.onProgress?.(status.progress);
+
+            // Let pending I/O (including a client disconnect) run between steps
+            await new Promise((resolve) => setImmediate(resolve));
+        }
+
+        // Drop the job status fields from the result
+        const { win, tie, lose, samples, tie_probability, chops, counts } =
+            job.result();
+        return { win, tie, lose, samples, tie_probability, chops, counts };
+    } finally {
+        job.free();
     }
+}
+
+/**
+ * Calculate equity using Rust WASM Monte Carlo sampling
+ * Deterministic for a given seed; the result includes per-player standard
+ * errors and 95% confidence intervals of the equity
+ */
+export async function calculateEquityMonteCarloRust(
+    players: readonly Hole[],
+    board: Board,
+    dead: readonly Card[] = [],
+    samples: number = DEFAULT_MONTE_CARLO_SAMPLES,
+    seed: number = 0
+): Promise<EquityResult> {
+    const arrays = prepareEquityArrays(players, board, dead);
+
+    // Initialize WASM module
+    const wasm = await initWasmModule();
+
+    // Call WASM function (throws on invalid input)
+    return wasm.calculate_equity_monte_carlo(
+        arrays.playerRanks,
+        arrays.playerSuits,
+        arrays.boardRanks,
+        arrays.boardSuits,
+        arrays.deadRanks,
+        arrays.deadSuits,
+        players.length,
+        samples,
+        seed
+    );
+}
+
+/**
+ * Calculate equity against random (unknown) opponent hands using Rust WASM
+ * The known players are followed by `randomOpponents` seats dealt at random
+ * from the remaining deck; exact when feasible, sampled otherwise
+ */
+export async function calculateEquityVsRandomRust(
+    players: readonly Hole[],
+    randomOpponents: number,
+    board: Board,
+    dead: readonly Card[] = [],
+    samples: number = DEFAULT_MONTE_CARLO_SAMPLES,
+    seed: number = 0
+): Promise<EquityResult> {
+    const arrays = prepareEquityArrays(players, board, dead);
+
+    // Random seats are marked with rank 0 for both cards
+    const randomCards = new Uint8Array(randomOpponents * 2);
+    const playerRanks = new Uint8Array([...arrays.playerRanks, ...randomCards]);
+    const playerSuits = new Uint8Array([...arrays.playerSuits, ...randomCards]);
 
     // Initialize WASM module
     const wasm = await initWasmModule();
 
-    // Call WASM function
-    const resultJson = wasm.calculate_preflop_equity(
-        new Uint8Array(playerRanks),
-        new Uint8Array(playerSuits),
-        new Uint8Array(deckRanks),
-        new Uint8Array(deckSuits),
-        numPlayers,
-        missing
+    // Call WASM function (throws on invalid input)
+    return wasm.calculate_equity_vs_random(
+        playerRanks,
+        playerSuits,
+        arrays.boardRanks,
+        arrays.boardSuits,
+        arrays.deadRanks,
+        arrays.deadSuits,
+        players.length + randomOpponents,
+        samples,
+        seed
     );
+}
 
-    // Parse JSON result
-    const result = JSON.parse(resultJson) as EquityResult;
+/**
+ * Stopping limits for adaptive sampling (0 disables a limit)
+ */
+export interface AdaptiveLimits {
+    targetPrecision: number; // max 95% confidence half-width, e.g. 0.001 for +/-0.1%
+    timeBudgetMs: number;
+    maxSamples: number;
+}
 
-    return result;
+/**
+ * Calculate equity using Rust WASM adaptive sampling
+ * Samples until the precision target, time budget or sample cap is reached;
+ * the result reports which limit stopped it in `stop_reason`
+ */
+export async function calculateEquityAdaptiveRust(
+    players: readonly Hole[],
+    board: Board,
+    dead: readonly Card[],
+    limits: AdaptiveLimits,
+    seed: number = 0
+): Promise<EquityResult> {
+    const arrays = prepareEquityArrays(players, board, dead);
+
+    // Initialize WASM module
+    const wasm = await initWasmModule();
+
+    // Call WASM function (throws on invalid input)
+    return wasm.calculate_equity_adaptive(
+        arrays.playerRanks,
+        arrays.playerSuits,
+        arrays.boardRanks,
+        arrays.boardSuits,
+        arrays.deadRanks,
+        arrays.deadSuits,
+        players.length,
+        limits.targetPrecision,
+        limits.timeBudgetMs,
+        limits.maxSamples,
+        seed
+    );
 }
 
 /**
@@ -163,7 +386,7 @@ export async function calculateTurnOuts(
     hero: Hole,
     villain: Hole,
     board: Board
-): Promise<any> {
+): Promise<CalculateOutsResponse> {
     // Validate inputs
     if (hero.cards.length !== 2) {
         throw new Error("Hero must have exactly 2 cards");
@@ -192,8 +415,8 @@ export async function calculateTurnOuts(
     // Initialize WASM module
     const wasm = await initWasmModule();
 
-    // Call WASM function
-    const resultJson = wasm.compute_turn_outs(
+    // Call WASM function (throws on invalid input)
+    const result: CalculateOutsResponse = wasm.compute_turn_outs(
         new Uint8Array(heroRanks),
         new Uint8Array(heroSuits),
         new Uint8Array(villainRanks),
@@ -202,14 +425,6 @@ export async function calculateTurnOuts(
         new Uint8Array(boardSuits)
     );
 
-    // Parse JSON result
-    const result = JSON.parse(resultJson);
-
-    // Check for error
-    if (result.error) {
-        throw new Error(result.error);
-    }
-
     // Convert OutCard[] to Card[] for easier use
     const numberToSuit = (suit: number): "c" | "d" | "h" | "s" => {
         switch (suit) {
@@ -226,15 +441,121 @@ export async function calculateTurnOuts(
         }
     };
 
-    result.win_outs_cards = result.win_outs.map((out: any) => ({
+    result.win_outs_cards = result.win_outs.map((out: OutCard) => ({
         rank: out.rank,
         suit: numberToSuit(out.suit),
     }));
 
-    result.tie_outs_cards = result.tie_outs.map((out: any) => ({
+    result.tie_outs_cards = result.tie_outs.map((out: OutCard) => ({
         rank: out.rank,
         suit: numberToSuit(out.suit),
     }));
 
     return result;
 }
+
+/**
+ * Narrow a Rust hand value to a HandRank: Rust only returns categories 0-9 and
+ * tiebreak ranks 2-14
+ */
+function toHandRank({ category, tiebreak }: WasmEquity.HandValue): HandRank {
+    return { category, tiebreak } as HandRank;
+}
+
+/**
+ * Evaluate the best five-card hand among 5-7 cards using Rust WASM
+ * Returns the category (0 high card ... 9 royal flush) and tiebreak ranks;
+ * throws for fewer than 5 or more than 7 cards, or invalid/duplicate cards
+ */
+export async function evaluateHandRust(
+    cards: readonly Card[]
+): Promise<HandRank> {
+    // Initialize WASM module
+    const wasm = await initWasmModule();
+
+    return toHandRank(
+        wasm.evaluate_hand(
+            new Uint8Array(cards.map((c) => c.rank)),
+            new Uint8Array(cards.map((c) => suitToNumber(c.suit)))
+        )
+    );
+}
+
+/**
+ * Evaluate 5-7 cards using Rust WASM and pick the five cards that make the hand
+ * bestCards is in tiebreak order: the made hand, then kickers; straights run from
+ * the high card down, so the wheel (5-4-3-2-A) ends with its ace
+ */
+export async function bestHandRust(
+    cards: readonly Card[]
+): Promise<{ handRank: HandRank; bestCards: Card[] }> {
+    // Initialize WASM module
+    const wasm = await initWasmModule();
+
+    const value = wasm.best_hand(
+        new Uint8Array(cards.map((c) => c.rank)),
+        new Uint8Array(cards.map((c) => suitToNumber(c.suit)))
+    );
+    return {
+        handRank: toHandRank(value),
+        bestCards: (value.best_five ?? []).map((index) => cards[index]),
+    };
+}
+
+/**
+ * Describe a hand rank in words using Rust WASM, e.g. "Pair of Queens, Ace kicker"
+ * Uses the catalog registered for locale, then its language ("de" for "de-AT"),
+ * then the built-in English one; throws if the tiebreak does not fit the category
+ */
+export async function describeHandRust(
+    handRank: HandRank,
+    locale?: string
+): Promise<string> {
+    // Initialize WASM module
+    const wasm = await initWasmModule();
+
+    return wasm.describe_hand(
+        handRank.category,
+        new Uint8Array(handRank.tiebreak),
+        locale
+    );
+}
+
+/**
+ * Register the message catalog describeHandRust uses for a locale
+ * Replaces any catalog registered for the same locale ("en" overrides the
+ * built-in English wording); throws if the catalog is incomplete
+ */
+export async function registerHandCatalogRust(
+    catalog: HandMessageCatalog
+): Promise<void> {
+    // Initialize WASM module
+    const wasm = await initWasmModule();
+
+    // Rust rejects rank and category lists of the wrong length
+    wasm.register_hand_catalog(catalog as WasmEquity.MessageCatalog);
+}
+
+/**
+ * Compare two hand ranks: by category, then tiebreak ranks left to right
+ * @returns -1 if a < b, 0 if a === b, 1 if a > b
+ */
+export function compareHandRanks(a: HandRank, b: HandRank): number {
+    if (a.category !== b.category) {
+        return a.category > b.category ? 1 : -1;
+    }
+
+    for (
+        let i = 0;
+        i < Math.max(a.tiebreak.length, b.tiebreak.length);
+        i++
+    ) {
+        const aRank = a.tiebreak[i] ?? 0;
+        const bRank = b.tiebreak[i] ?? 0;
+        if (aRank !== bRank) {
+            return aRank > bRank ? 1 : -1;
+        }
+    }
+
+    return 0;
+}
@@ -1,22 +1,54 @@
 import { HandRank, Card, parseCard } from "@common/interfaces";
-import { hand } from "./hand";
-
-describe("Hand", () => {
-    describe("evaluate7", () => {
-        it("should throw error if not exactly 7 cards", () => {
-            expect(() => hand.evaluate7([])).toThrow(
-                "evaluate7 requires exactly 7 cards"
-            );
-            expect(() => hand.evaluate7([parseCard("2c")])).toThrow(
-                "evaluate7 requires exactly 7 cards"
+import {
+    evaluateHandRust,
+    bestHandRust,
+    describeHandRust,
+    registerHandCatalogRust,
+    compareHandRanks,
+} from "./equityRust";
+
+describe("Hand evaluation (Rust WASM)", () => {
+    describe("evaluateHandRust", () => {
+        it("should throw unless given 5 to 7 cards", async () => {
+            await expect(evaluateHandRust([])).rejects.toThrow(
+                "A hand must have 5 to 7 cards"
             );
-            expect(() =>
-                hand.evaluate7(new Array(8).fill(parseCard("2c")))
-            ).toThrow("evaluate7 requires exactly 7 cards");
+            await expect(
+                evaluateHandRust([parseCard("2c"), parseCard("3d")])
+            ).rejects.toThrow("A hand must have 5 to 7 cards");
+            await expect(
+                evaluateHandRust(
+                    ["2c", "3d", "4h", "5s", "6c", "7d", "8h", "9s"].map(
+                        parseCard
+                    )
+                )
+            ).rejects.toThrow("A hand must have 5 to 7 cards");
+        });
+
+        it("should throw for duplicate cards", async () => {
+            await expect(
+                evaluateHandRust(["2c", "2c", "4h", "5s", "6c"].map(parseCard))
+            ).rejects.toThrow("Duplicate card found: 2c");
+        });
+
+        it("should evaluate 5 and 6 card hands", async () => {
+            // Flop: two pair, Kings and Sevens with an Ace kicker
+            const flop = ["13h", "13d", "7c", "7s", "14h"].map(parseCard);
+            expect(await evaluateHandRust(flop)).toEqual({
+                category: 2,
+                tiebreak: [13, 7, 14],
+            });
+
+            // Turn: the sixth card makes a wheel
+            const turn = ["14h", "2d", "3c", "4s", "5h", "9d"].map(parseCard);
+            expect(await evaluateHandRust(turn)).toEqual({
+                category: 4,
+                tiebreak: [5],
+            });
         });
 
         describe("Royal Flush", () => {
-            it("should detect royal flush in hearts", () => {
+            it("should detect royal flush in hearts", async () => {
                 const cards: Card[] = [
                     parseCard("14h"), // Ace
                     parseCard("13h"), // King
@@ -26,12 +58,12 @@ describe("Hand", () => {
                     parseCard("2c"), // extra
                     parseCard("3d"), // extra
                 ];
-                const result = hand.evaluate7(cards);
+                const result = await evaluateHandRust(cards);
                 expect(result.category).toBe(9);
                 expect(result.tiebreak).toEqual([]);
             });
 
-            it("should detect royal flush with extra cards", () => {
+            it("should detect royal flush with extra cards", async () => {
                 const cards: Card[] = [
                     parseCard("14s"),
                     parseCard("13s"),
@@ -41,13 +73,13 @@ describe("Hand", () => {
                     parseCard("9s"),
                     parseCard("8s"),
                 ];
-                const result = hand.evaluate7(cards);
+                const result = await evaluateHandRust(cards);
                 expect(result.category).toBe(9);
             });
         });
 
         describe("Straight Flush", () => {
-            it("should detect straight flush (9-high)", () => {
+            it("should detect straight flush (9-high)", async () => {
                 const cards: Card[] = [
                     parseCard("9h"),
                     parseCard("8h"),
@@ -57,12 +89,12 @@ describe("Hand", () => {
                     parseCard("2c"),
                     parseCard("3d"),
                 ];
-                const result = hand.evaluate7(cards);
+                const result = await evaluateHandRust(cards);
                 expect(result.category).toBe(8);
                 expect(result.tiebreak).toEqual([9]);
             });
 
-            it("should detect straight flush (A-2-3-4-5 wheel)", () => {
+            it("should detect straight flush (A-2-3-4-5 wheel)", async () => {
                 const cards: Card[] = [
                     parseCard("14c"),
                     parseCard("5c"),
@@ -72,12 +104,12 @@ describe("Hand", () => {
                     parseCard("10d"),
                     parseCard("11d"),
                 ];
-                const result = hand.evaluate7(cards);
+                const result = await evaluateHandRust(cards);
                 expect(result.category).toBe(8);
                 expect(result.tiebreak).toEqual([5]); // Wheel high card is 5
             });
 
-            it("should prefer straight flush over flush", () => {
+            it("should prefer straight flush over flush", async () => {
                 const cards: Card[] = [
                     parseCard("8d"),
                     parseCard("7d"),
@@ -87,14 +119,14 @@ describe("Hand", () => {
                     parseCard("3d"),
                     parseCard("2d"),
                 ];
-                const result = hand.evaluate7(cards);
+                const result = await evaluateHandRust(cards);
                 expect(result.category).toBe(8);
                 expect(result.tiebreak).toEqual([8]);
             });
         });
 
         describe("Four of a Kind", () => {
-            it("should detect four of a kind (Kings)", () => {
+            it("should detect four of a kind (Kings)", async () => {
                 const cards: Card[] = [
                     parseCard("13h"),
                     parseCard("13d"),
@@ -104,12 +136,12 @@ describe("Hand", () => {
                     parseCard("2c"),
                     parseCard("3d"),
                 ];
-                const result = hand.evaluate7(cards);
+                const result = await evaluateHandRust(cards);
                 expect(result.category).toBe(7);
                 expect(result.tiebreak).toEqual([13, 7]);
             });
 
-            it("should use highest kicker for four of a kind", () => {
+            it("should use highest kicker for four of a kind", async () => {
                 const cards: Card[] = [
                     parseCard("10h"),
                     parseCard("10d"),
@@ -119,14 +151,14 @@ describe("Hand", () => {
                     parseCard("9h"),
                     parseCard("2c"),
                 ];
-                const result = hand.evaluate7(cards);
+                const result = await evaluateHandRust(cards);
                 expect(result.category).toBe(7);
                 expect(result.tiebreak).toEqual([10, 14]);
             });
         });
 
         describe("Full House", () => {
-            it("should detect full house (Aces over Kings)", () => {
+            it("should detect full house (Aces over Kings)", async () => {
                 const cards: Card[] = [
                     parseCard("14h"),
                     parseCard("14d"),
@@ -136,12 +168,12 @@ describe("Hand", () => {
                     parseCard("2c"),
                     parseCard("3d"),
                 ];
-                const result = hand.evaluate7(cards);
+                const result = await evaluateHandRust(cards);
                 expect(result.category).toBe(6);
                 expect(result.tiebreak).toEqual([14, 13]);
             });
 
-            it("should prefer higher three of a kind in full house", () => {
+            it("should prefer higher three of a kind in full house", async () => {
                 const cards: Card[] = [
                     parseCard("12h"),
                     parseCard("12d"),
@@ -151,14 +183,14 @@ describe("Hand", () => {
                     parseCard("11h"),
                     parseCard("11d"),
                 ];
-                const result = hand.evaluate7(cards);
+                const result = await evaluateHandRust(cards);
                 expect(result.category).toBe(6);
                 expect(result.tiebreak).toEqual([12, 14]); // Queens over Aces
             });
         });
 
         describe("Flush", () => {
-            it("should detect flush", () => {
+            it("should detect flush", async () => {
                 const cards: Card[] = [
                     parseCard("14h"),
                     parseCard("12h"),
@@ -168,12 +200,12 @@ describe("Hand", () => {
                     parseCard("2c"),
                     parseCard("3d"),
                 ];
-                const result = hand.evaluate7(cards);
+                const result = await evaluateHandRust(cards);
                 expect(result.category).toBe(5);
                 expect(result.tiebreak).toEqual([14, 12, 10, 7, 5]);
             });
 
-            it("should use highest 5 cards for flush", () => {
+            it("should use highest 5 cards for flush", async () => {
                 const cards: Card[] = [
                     parseCard("14h"),
                     parseCard("12h"),
@@ -183,14 +215,14 @@ describe("Hand", () => {
                     parseCard("3h"),
                     parseCard("2c"),
                 ];
-                const result = hand.evaluate7(cards);
+                const result = await evaluateHandRust(cards);
                 expect(result.category).toBe(5);
                 expect(result.tiebreak).toEqual([14, 12, 10, 7, 5]); // Should pick highest 5
             });
         });
 
         describe("Straight", () => {
-            it("should detect straight (10-high)", () => {
+            it("should detect straight (10-high)", async () => {
                 const cards: Card[] = [
                     parseCard("10h"),
                     parseCard("9d"),
@@ -200,12 +232,12 @@ describe("Hand", () => {
                     parseCard("2c"),
                     parseCard("3d"),
                 ];
-                const result = hand.evaluate7(cards);
+                const result = await evaluateHandRust(cards);
                 expect(result.category).toBe(4);
                 expect(result.tiebreak).toEqual([10]);
             });
 
-            it("should detect wheel straight (A-2-3-4-5)", () => {
+            it("should detect wheel straight (A-2-3-4-5)", async () => {
                 const cards: Card[] = [
                     parseCard("14h"),
                     parseCard("5d"),
@@ -215,12 +247,12 @@ describe("Hand", () => {
                     parseCard("10c"),
                     parseCard("11d"),
                 ];
-                const result = hand.evaluate7(cards);
+                const result = await evaluateHandRust(cards);
                 expect(result.category).toBe(4);
                 expect(result.tiebreak).toEqual([5]);
             });
 
-            it("should prefer straight over pair", () => {
+            it("should prefer straight over pair", async () => {
                 const cards: Card[] = [
                     parseCard("9h"),
                     parseCard("8d"),
@@ -230,13 +262,13 @@ describe("Hand", () => {
                     parseCard("14c"),
                     parseCard("14d"),
                 ];
-                const result = hand.evaluate7(cards);
+                const result = await evaluateHandRust(cards);
                 expect(result.category).toBe(4);
             });
         });
 
         describe("Three of a Kind", () => {
-            it("should detect three of a kind", () => {
+            it("should detect three of a kind", async () => {
                 const cards: Card[] = [
                     parseCard("10h"),
                     parseCard("10d"),
@@ -246,12 +278,12 @@ describe("Hand", () => {
                     parseCard("2c"),
                     parseCard("3d"),
                 ];
-                const result = hand.evaluate7(cards);
+                const result = await evaluateHandRust(cards);
                 expect(result.category).toBe(3);
                 expect(result.tiebreak).toEqual([10, 7, 5]);
             });
 
-            it("should use highest kickers for three of a kind", () => {
+            it("should use highest kickers for three of a kind", async () => {
                 const cards: Card[] = [
                     parseCard("8h"),
                     parseCard("8d"),
@@ -261,14 +293,14 @@ describe("Hand", () => {
                     parseCard("12c"),
                     parseCard("2d"),
                 ];
-                const result = hand.evaluate7(cards);
+                const result = await evaluateHandRust(cards);
                 expect(result.category).toBe(3);
                 expect(result.tiebreak).toEqual([8, 14, 13]);
             });
         });
 
         describe("Two Pair", () => {
-            it("should detect two pair", () => {
+            it("should detect two pair", async () => {
                 const cards: Card[] = [
                     parseCard("10h"),
                     parseCard("10d"),
@@ -278,12 +310,12 @@ describe("Hand", () => {
                     parseCard("2c"),
                     parseCard("3d"),
                 ];
-                const result = hand.evaluate7(cards);
+                const result = await evaluateHandRust(cards);
                 expect(result.category).toBe(2);
                 expect(result.tiebreak).toEqual([10, 7, 5]);
             });
 
-            it("should use highest pairs and kicker", () => {
+            it("should use highest pairs and kicker", async () => {
                 const cards: Card[] = [
                     parseCard("14h"),
                     parseCard("14d"),
@@ -293,14 +325,14 @@ describe("Hand", () => {
                     parseCard("11c"),
                     parseCard("2d"),
                 ];
-                const result = hand.evaluate7(cards);
+                const result = await evaluateHandRust(cards);
                 expect(result.category).toBe(2);
                 expect(result.tiebreak).toEqual([14, 13, 12]);
             });
         });
 
         describe("Pair", () => {
-            it("should detect pair", () => {
+            it("should detect pair", async () => {
                 const cards: Card[] = [
                     parseCard("10h"),
                     parseCard("10d"),
@@ -310,12 +342,12 @@ describe("Hand", () => {
                     parseCard("2c"),
                     parseCard("14d"),
                 ];
-                const result = hand.evaluate7(cards);
+                const result = await evaluateHandRust(cards);
                 expect(result.category).toBe(1);
                 expect(result.tiebreak).toEqual([10, 14, 7, 5]);
             });
 
-            it("should use highest kickers for pair", () => {
+            it("should use highest kickers for pair", async () => {
                 const cards: Card[] = [
                     parseCard("8h"),
                     parseCard("8d"),
@@ -325,14 +357,14 @@ describe("Hand", () => {
                     parseCard("11c"),
                     parseCard("2d"),
                 ];
-                const result = hand.evaluate7(cards);
+                const result = await evaluateHandRust(cards);
                 expect(result.category).toBe(1);
                 expect(result.tiebreak).toEqual([8, 14, 13, 12]);
             });
         });
 
         describe("High Card", () => {
-            it("should detect high card", () => {
+            it("should detect high card", async () => {
                 const cards: Card[] = [
                     parseCard("14h"),
                     parseCard("10d"),
@@ -342,12 +374,12 @@ describe("Hand", () => {
                     parseCard("2c"),
                     parseCard("8d"), // Changed from 4 to avoid wheel straight
                 ];
-                const result = hand.evaluate7(cards);
+                const result = await evaluateHandRust(cards);
                 expect(result.category).toBe(0);
                 expect(result.tiebreak).toEqual([14, 10, 8, 7, 5]);
             });
 
-            it("should use highest 5 cards for high card", () => {
+            it("should use highest 5 cards for high card", async () => {
                 const cards: Card[] = [
                     parseCard("14h"),
                     parseCard("13d"),
@@ -357,14 +389,14 @@ describe("Hand", () => {
                     parseCard("7c"),
                     parseCard("2d"),
                 ];
-                const result = hand.evaluate7(cards);
+                const result = await evaluateHandRust(cards);
                 expect(result.category).toBe(0);
                 expect(result.tiebreak).toEqual([14, 13, 12, 9, 8]);
             });
         });
 
         describe("Edge cases", () => {
-            it("should handle case where best hand is not obvious (multiple possibilities)", () => {
+            it("should handle case where best hand is not obvious (multiple possibilities)", async () => {
                 // Hand that could be flush or straight - should pick best combination
                 const cards: Card[] = [
                     parseCard("9h"),
@@ -375,13 +407,13 @@ describe("Hand", () => {
                     parseCard("4h"),
                     parseCard("2c"),
                 ];
-                const result = hand.evaluate7(cards);
+                const result = await evaluateHandRust(cards);
                 // Should be straight flush (9-high), not just flush
                 expect(result.category).toBe(8);
                 expect(result.tiebreak).toEqual([9]);
             });
 
-            it("should handle case with multiple pairs where best 5-card hand uses specific pairs", () => {
+            it("should handle case with multiple pairs where best 5-card hand uses specific pairs", async () => {
                 const cards: Card[] = [
                     parseCard("14h"),
                     parseCard("14d"),
@@ -391,13 +423,13 @@ describe("Hand", () => {
                     parseCard("12d"),
                     parseCard("11c"),
                 ];
-                const result = hand.evaluate7(cards);
+                const result = await evaluateHandRust(cards);
                 // Should pick highest two pair: Aces and Kings with Queen kicker
                 expect(result.category).toBe(2);
                 expect(result.tiebreak).toEqual([14, 13, 12]);
             });
 
-            it("should handle case where full house is possible with different three-of-a-kinds", () => {
+            it("should handle case where full house is possible with different three-of-a-kinds", async () => {
                 const cards: Card[] = [
                     parseCard("14h"),
                     parseCard("14d"),
@@ -407,7 +439,7 @@ describe("Hand", () => {
                     parseCard("12c"),
                     parseCard("12d"),
                 ];
-                const result = hand.evaluate7(cards);
+                const result = await evaluateHandRust(cards);
                 // Should pick Aces over Kings (not Aces over Queens)
                 expect(result.category).toBe(6);
                 expect(result.tiebreak).toEqual([14, 13]);
@@ -415,49 +447,183 @@ describe("Hand", () => {
         });
     });
 
-    describe("compareRanks", () => {
+    describe("bestHandRust", () => {
+        it("should return the pair first, then kickers high to low", async () => {
+            const cards = ["14h", "13d", "3c", "7s", "14s", "2d", "9h"].map(
+                parseCard
+            );
+            const { handRank, bestCards } = await bestHandRust(cards);
+            expect(handRank).toEqual({ category: 1, tiebreak: [14, 13, 9, 7] });
+            expect(bestCards).toEqual(
+                ["14h", "14s", "13d", "9h", "7s"].map(parseCard)
+            );
+        });
+
+        it("should put the ace of a wheel last", async () => {
+            const cards = ["14c", "2d", "3h", "4s", "5c", "5d", "9s"].map(
+                parseCard
+            );
+            const { handRank, bestCards } = await bestHandRust(cards);
+            expect(handRank).toEqual({ category: 4, tiebreak: [5] });
+            expect(bestCards).toEqual(
+                ["5c", "4s", "3h", "2d", "14c"].map(parseCard)
+            );
+        });
+
+        it("should take every card of a flush from the flush suit", async () => {
+            const cards = ["9c", "8c", "6c", "4c", "2c", "14c", "14d"].map(
+                parseCard
+            );
+            const { handRank, bestCards } = await bestHandRust(cards);
+            expect(handRank.category).toBe(5);
+            expect(bestCards).toEqual(
+                ["14c", "9c", "8c", "6c", "4c"].map(parseCard)
+            );
+        });
+
+        it("should use the trips then the pair of a full house", async () => {
+            const cards = ["2c", "2d", "2h", "5s", "5c", "5d", "9s"].map(
+                parseCard
+            );
+            const { handRank, bestCards } = await bestHandRust(cards);
+            expect(handRank).toEqual({ category: 6, tiebreak: [5, 2] });
+            expect(bestCards).toEqual(
+                ["5s", "5c", "5d", "2c", "2d"].map(parseCard)
+            );
+        });
+    });
+
+    describe("describeHandRust", () => {
+        it("should name the made hand and its kicker", async () => {
+            expect(
+                await describeHandRust({ category: 6, tiebreak: [14, 13] })
+            ).toBe("Full house, Aces full of Kings");
+            expect(
+                await describeHandRust({
+                    category: 1,
+                    tiebreak: [12, 14, 7, 5],
+                })
+            ).toBe("Pair of Queens, Ace kicker");
+            expect(await describeHandRust({ category: 9, tiebreak: [] })).toBe(
+                "Royal flush"
+            );
+        });
+
+        it("should mark the wheel", async () => {
+            expect(await describeHandRust({ category: 4, tiebreak: [5] })).toBe(
+                "Straight, Five high (wheel)"
+            );
+        });
+
+        it("should reject a tiebreak that does not fit the category", async () => {
+            await expect(
+                describeHandRust({ category: 6, tiebreak: [14] })
+            ).rejects.toThrow("Invalid hand rank");
+        });
+
+        it("should use a registered catalog for its language", async () => {
+            await registerHandCatalogRust({
+                locale: "de",
+                ranks: [
+                    "Zwei",
+                    "Drei",
+                    "Vier",
+                    "Fünf",
+                    "Sechs",
+                    "Sieben",
+                    "Acht",
+                    "Neun",
+                    "Zehn",
+                    "Bube",
+                    "Dame",
+                    "König",
+                    "Ass",
+                ],
+                rank_plurals: [
+                    "Zweien",
+                    "Dreien",
+                    "Vieren",
+                    "Fünfen",
+                    "Sechsen",
+                    "Siebenen",
+                    "Achten",
+                    "Neunen",
+                    "Zehnen",
+                    "Buben",
+                    "Damen",
+                    "Könige",
+                    "Asse",
+                ],
+                categories: [
+                    "{0} hoch",
+                    "Paar {0s}",
+                    "Zwei Paare, {0s} und {1s}",
+                    "Drilling, {0s}",
+                    "Straße, {0} hoch",
+                    "Flush, {0} hoch",
+                    "Full House, {0s} und {1s}",
+                    "Vierling, {0s}",
+                    "Straight Flush, {0} hoch",
+                    "Royal Flush",
+                ],
+                with_kicker: "{hand}, Kicker {kicker}",
+                wheel: "{hand} (Wheel)",
+            });
+            expect(
+                await describeHandRust(
+                    { category: 1, tiebreak: [12, 14, 7, 5] },
+                    "de-AT"
+                )
+            ).toBe("Paar Damen, Kicker Ass");
+            expect(
+                await describeHandRust({ category: 6, tiebreak: [14, 13] }, "fr")
+            ).toBe("Full house, Aces full of Kings");
+        });
+    });
+
+    describe("compareHandRanks", () => {
         it("should return 1 when first hand has higher category", () => {
             const hand1: HandRank = { category: 8, tiebreak: [9] }; // Straight Flush
             const hand2: HandRank = { category: 7, tiebreak: [13, 7] }; // Four of a Kind
-            expect(hand.compareRanks(hand1, hand2)).toBe(1);
+            expect(compareHandRanks(hand1, hand2)).toBe(1);
         });
 
         it("should return -1 when second hand has higher category", () => {
             const hand1: HandRank = { category: 1, tiebreak: [10, 14, 7, 5] }; // Pair
             const hand2: HandRank = { category: 6, tiebreak: [14, 13] }; // Full House
-            expect(hand.compareRanks(hand1, hand2)).toBe(-1);
+            expect(compareHandRanks(hand1, hand2)).toBe(-1);
         });
 
         it("should return 0 for identical royal flushes", () => {
             const hand1: HandRank = { category: 9, tiebreak: [] };
             const hand2: HandRank = { category: 9, tiebreak: [] };
-            expect(hand.compareRanks(hand1, hand2)).toBe(0);
+            expect(compareHandRanks(hand1, hand2)).toBe(0);
         });
 
         describe("Same category comparisons", () => {
             it("should compare straight flush by high card", () => {
                 const hand1: HandRank = { category: 8, tiebreak: [10] }; // 10-high straight flush
                 const hand2: HandRank = { category: 8, tiebreak: [9] }; // 9-high straight flush
-                expect(hand.compareRanks(hand1, hand2)).toBe(1);
-                expect(hand.compareRanks(hand2, hand1)).toBe(-1);
+                expect(compareHandRanks(hand1, hand2)).toBe(1);
+                expect(compareHandRanks(hand2, hand1)).toBe(-1);
             });
 
             it("should compare four of a kind by rank then kicker", () => {
                 const hand1: HandRank = { category: 7, tiebreak: [13, 14] }; // Kings with Ace
                 const hand2: HandRank = { category: 7, tiebreak: [13, 12] }; // Kings with Queen
-                expect(hand.compareRanks(hand1, hand2)).toBe(1);
+                expect(compareHandRanks(hand1, hand2)).toBe(1);
 
                 const hand3: HandRank = { category: 7, tiebreak: [14, 13] }; // Aces with King
-                expect(hand.compareRanks(hand3, hand1)).toBe(1);
+                expect(compareHandRanks(hand3, hand1)).toBe(1);
             });
 
             it("should compare full house by three of a kind then pair", () => {
                 const hand1: HandRank = { category: 6, tiebreak: [14, 13] }; // Aces over Kings
                 const hand2: HandRank = { category: 6, tiebreak: [14, 12] }; // Aces over Queens
-                expect(hand.compareRanks(hand1, hand2)).toBe(1);
+                expect(compareHandRanks(hand1, hand2)).toBe(1);
 
                 const hand3: HandRank = { category: 6, tiebreak: [13, 14] }; // Kings over Aces
-                expect(hand.compareRanks(hand1, hand3)).toBe(1); // Aces over Kings beats Kings over Aces
+                expect(compareHandRanks(hand1, hand3)).toBe(1); // Aces over Kings beats Kings over Aces
             });
 
             it("should compare flush by highest cards", () => {
@@ -469,43 +635,43 @@ describe("Hand", () => {
                     category: 5,
                     tiebreak: [14, 13, 12, 11, 8],
                 };
-                expect(hand.compareRanks(hand1, hand2)).toBe(1);
+                expect(compareHandRanks(hand1, hand2)).toBe(1);
 
                 const hand3: HandRank = {
                     category: 5,
                     tiebreak: [14, 13, 12, 10, 9],
                 };
-                expect(hand.compareRanks(hand1, hand3)).toBe(1); // 11 beats 10
+                expect(compareHandRanks(hand1, hand3)).toBe(1); // 11 beats 10
             });
 
             it("should compare straight by high card", () => {
                 const hand1: HandRank = { category: 4, tiebreak: [10] }; // 10-high
                 const hand2: HandRank = { category: 4, tiebreak: [9] }; // 9-high
-                expect(hand.compareRanks(hand1, hand2)).toBe(1);
+                expect(compareHandRanks(hand1, hand2)).toBe(1);
 
                 const hand3: HandRank = { category: 4, tiebreak: [5] }; // Wheel
-                expect(hand.compareRanks(hand1, hand3)).toBe(1); // 10-high beats wheel
+                expect(compareHandRanks(hand1, hand3)).toBe(1); // 10-high beats wheel
             });
 
             it("should compare three of a kind by rank then kickers", () => {
                 const hand1: HandRank = { category: 3, tiebreak: [10, 14, 13] }; // Tens with A-K
                 const hand2: HandRank = { category: 3, tiebreak: [10, 14, 12] }; // Tens with A-Q
-                expect(hand.compareRanks(hand1, hand2)).toBe(1);
+                expect(compareHandRanks(hand1, hand2)).toBe(1);
 
                 const hand3: HandRank = { category: 3, tiebreak: [14, 13, 12] }; // Aces
-                expect(hand.compareRanks(hand3, hand1)).toBe(1);
+                expect(compareHandRanks(hand3, hand1)).toBe(1);
             });
 
             it("should compare two pair by higher pair, then lower pair, then kicker", () => {
                 const hand1: HandRank = { category: 2, tiebreak: [14, 13, 12] }; // A-K with Q
                 const hand2: HandRank = { category: 2, tiebreak: [14, 13, 11] }; // A-K with J
-                expect(hand.compareRanks(hand1, hand2)).toBe(1);
+                expect(compareHandRanks(hand1, hand2)).toBe(1);
 
                 const hand3: HandRank = { category: 2, tiebreak: [14, 12, 13] }; // A-Q with K
-                expect(hand.compareRanks(hand1, hand3)).toBe(1); // A-K beats A-Q
+                expect(compareHandRanks(hand1, hand3)).toBe(1); // A-K beats A-Q
 
                 const hand4: HandRank = { category: 2, tiebreak: [13, 12, 14] }; // K-Q with A
-                expect(hand.compareRanks(hand1, hand4)).toBe(1); // A-K beats K-Q
+                expect(compareHandRanks(hand1, hand4)).toBe(1); // A-K beats K-Q
             });
 
             it("should compare pair by rank then kickers", () => {
@@ -517,13 +683,13 @@ describe("Hand", () => {
                     category: 1,
                     tiebreak: [10, 14, 13, 11],
                 }; // Tens with A-K-J
-                expect(hand.compareRanks(hand1, hand2)).toBe(1);
+                expect(compareHandRanks(hand1, hand2)).toBe(1);
 
                 const hand3: HandRank = {
                     category: 1,
                     tiebreak: [14, 13, 12, 11],
                 }; // Aces
-                expect(hand.compareRanks(hand3, hand1)).toBe(1);
+                expect(compareHandRanks(hand3, hand1)).toBe(1);
             });
 
             it("should compare high card by highest cards", () => {
@@ -535,100 +701,20 @@ describe("Hand", () => {
                     category: 0,
                     tiebreak: [14, 13, 12, 11, 8],
                 };
-                expect(hand.compareRanks(hand1, hand2)).toBe(1);
+                expect(compareHandRanks(hand1, hand2)).toBe(1);
 
                 const hand3: HandRank = {
                     category: 0,
                     tiebreak: [14, 13, 12, 10, 9],
                 };
-                expect(hand.compareRanks(hand1, hand3)).toBe(1);
+                expect(compareHandRanks(hand1, hand3)).toBe(1);
             });
         });
 
         it("should return 0 for identical hands", () => {
             const hand1: HandRank = { category: 7, tiebreak: [13, 7] };
             const hand2: HandRank = { category: 7, tiebreak: [13, 7] };
-            expect(hand.compareRanks(hand1, hand2)).toBe(0);
-        });
-    });
-
-    describe("Symmetry Reduction (Memoization)", () => {
-        beforeEach(() => {
-            // Clear cache before each test
-            hand.clearCache();
-        });
-
-        it("should cache evaluations of isomorphic hands", () => {
-            // These hands are isomorphic (same ranks, different suits)
-            // Using 9-8-7-6-5-4-3 to get a 9-high straight flush (not royal flush)
-            const hand1: Card[] = [
-                parseCard("9h"),
-                parseCard("8h"),
-                parseCard("7h"),
-                parseCard("6h"),
-                parseCard("5h"),
-                parseCard("4h"),
-                parseCard("3h"),
-            ];
-
-            const hand2: Card[] = [
-                parseCard("9s"),
-                parseCard("8s"),
-                parseCard("7s"),
-                parseCard("6s"),
-                parseCard("5s"),
-                parseCard("4s"),
-                parseCard("3s"),
-            ];
-
-            // Both should evaluate to the same result
-            const result1 = hand.evaluate7(hand1);
-            const result2 = hand.evaluate7(hand2);
-
-            expect(result1).toEqual(result2);
-            expect(result1.category).toBe(8); // Straight flush
-
-            // Cache should have been used (check stats)
-            const stats = hand.getCacheStats();
-            // Should have cached at least one evaluation
-            expect(stats.size).toBeGreaterThan(0);
-        });
-
-        it("should return cached result for identical hand", () => {
-            // Using 9-8-7-6-5-4-3 to get a 9-high straight flush (not royal flush)
-            const cards: Card[] = [
-                parseCard("9h"),
-                parseCard("8h"),
-                parseCard("7h"),
-                parseCard("6h"),
-                parseCard("5h"),
-                parseCard("4h"),
-                parseCard("3h"),
-            ];
-
-            const result1 = hand.evaluate7(cards);
-            const result2 = hand.evaluate7([...cards]); // Same cards, new array
-
-            expect(result1).toEqual(result2);
-            expect(result1.category).toBe(8); // Straight flush
-        });
-
-        it("should handle cache clearing", () => {
-            const cards: Card[] = [
-                parseCard("14h"),
-                parseCard("13h"),
-                parseCard("12h"),
-                parseCard("11h"),
-                parseCard("10h"),
-                parseCard("9h"),
-                parseCard("8h"),
-            ];
-
-            hand.evaluate7(cards);
-            expect(hand.getCacheStats().size).toBeGreaterThan(0);
-
-            hand.clearCache();
-            expect(hand.getCacheStats().size).toBe(0);
+            expect(compareHandRanks(hand1, hand2)).toBe(0);
         });
     });
 });
@@ -1,2 +1 @@
 export * from "./equity";
-export * from "./hand";
@@ -0,0 +1,30 @@
+// Build script: decides whether the `embedded-preflop-table` feature has a table to embed
+//
+// The feature compiles preflop_table.bin into the module, but the file is generated
+// (`wasm-equity preflop-table`) and may not exist yet. Without it the feature builds
+// like the default (a table can still be loaded at runtime) and cargo prints a
+// warning, so `--all-features` builds work on a fresh checkout.
+
+use std::env;
+use std::path::Path;
+
+const TABLE_FILE: &str = "preflop_table.bin";
+
+fn main() {
+    println!("cargo::rustc-check-cfg=cfg(embedded_preflop_table)");
+    println!("cargo::rerun-if-changed=build.rs");
+    if env::var_os("CARGO_FEATURE_EMBEDDED_PREFLOP_TABLE").is_none() {
+        return;
+    }
+
+    println!("cargo::rerun-if-changed={}", TABLE_FILE);
+    if Path::new(TABLE_FILE).exists() {
+        println!("cargo::rustc-cfg=embedded_preflop_table");
+    } else {
+        println!(
+            "cargo::warning={} not found: building without an embedded preflop table \
+             (generate it with `cargo run --release -- preflop-table`)",
+            TABLE_FILE
+        );
+    }
+}
@@ -2,14 +2,14 @@
 
 # Check if WASM package already exists and is up-to-date
 PKG_DIR="pkg"
-SRC_FILE="src/lib.rs"
+SRC_DIR="src"
 CARGO_FILE="Cargo.toml"
 
 # Check if pkg directory exists and has required files
 if [ -d "$PKG_DIR" ] && [ -f "$PKG_DIR/wasm_equity.js" ] && [ -f "$PKG_DIR/wasm_equity_bg.wasm" ]; then
     # Check if source files are newer than pkg files (only if source files exist)
     NEEDS_REBUILD=false
-    if [ -f "$SRC_FILE" ] && [ "$SRC_FILE" -nt "$PKG_DIR/wasm_equity.js" ]; then
+    if [ -d "$SRC_DIR" ] && [ -n "$(find "$SRC_DIR" -newer "$PKG_DIR/wasm_equity.js" -print -quit)" ]; then
         NEEDS_REBUILD=true
     fi
     if [ -f "$CARGO_FILE" ] && [ "$CARGO_FILE" -nt "$PKG_DIR/wasm_equity.js" ]; then
@@ -68,7 +68,8 @@ fi
 
 # Build the WASM module
 echo "🔨 Building WASM module..."
-wasm-pack build --target nodejs --out-dir pkg
+# The wasm-bindgen exports are behind the `wasm` feature
+wasm-pack build --target nodejs --out-dir pkg -- --features wasm
 
 echo "✅ WASM build complete!"
 
@@ -123,8 +123,7 @@ async function comparePerformance() {
         new Uint8Array(playerSuits),
         new Uint8Array(deckRanks),
         new Uint8Array(deckSuits),
-        numPlayers,
-        missing
+        numPlayers
     );
     
     // Direct WASM benchmark (multiple runs for average)
@@ -132,20 +131,18 @@ async function comparePerformance() {
     const directTimes = [];
     for (let i = 0; i < 3; i++) {
         const start = performance.now();
-        const resultJson = wasmModule.calculate_preflop_equity(
+        const result = wasmModule.calculate_preflop_equity(
             new Uint8Array(playerRanks),
             new Uint8Array(playerSuits),
             new Uint8Array(deckRanks),
             new Uint8Array(deckSuits),
-            numPlayers,
-            missing
+            numPlayers
         );
         const end = performance.now();
         const elapsed = end - start;
         directTimes.push(elapsed);
         
         if (i === 0) {
-            const result = JSON.parse(resultJson);
             console.log(`   Run ${i + 1}: ${formatTime(elapsed)} (${formatNumber(result.samples)} samples)`);
         } else {
             console.log(`   Run ${i + 1}: ${formatTime(elapsed)}`);
@@ -166,7 +163,7 @@ async function comparePerformance() {
     for (let i = 0; i < 3; i++) {
         const start = performance.now();
         
-        // Simulate TypeScript wrapper: parse cards, convert arrays, call WASM, parse JSON
+        // Simulate TypeScript wrapper: parse cards, convert arrays, call WASM
         const playerRanksArray = [];
         const playerSuitsArray = [];
         for (const hole of players) {
@@ -179,16 +176,14 @@ async function comparePerformance() {
         const deckRanksArray = remainingDeck.map(c => c.rank);
         const deckSuitsArray = remainingDeck.map(c => c.suit);
         
-        const resultJson2 = wasmModule.calculate_preflop_equity(
+        const result = wasmModule.calculate_preflop_equity(
             new Uint8Array(playerRanksArray),
             new Uint8Array(playerSuitsArray),
             new Uint8Array(deckRanksArray),
             new Uint8Array(deckSuitsArray),
-            numPlayers,
-            missing
+            numPlayers
         );
-        
-        const result = JSON.parse(resultJson2);
+
         const end = performance.now();
         const elapsed = end - start;
         wrapperTimes.push(elapsed);
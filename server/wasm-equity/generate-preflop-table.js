@@ -0,0 +1,61 @@
+const path = require('path');
+const fs = require('fs');
+
+// Matchups generated per call (each one enumerates 1,712,304 boards)
+const CHUNK_SIZE = 100;
+
+// Helper function to format time
+function formatTime(ms) {
+    if (ms < 1000) {
+        return `${ms.toFixed(0)}ms`;
+    } else if (ms < 60 * 60 * 1000) {
+        return `${(ms / 60000).toFixed(1)}min`;
+    } else {
+        return `${(ms / 3600000).toFixed(1)}h`;
+    }
+}
+
+// Usage: node generate-preflop-table.js [output path]
+async function generatePreflopTable() {
+    const outputPath = process.argv[2] || path.join(__dirname, 'preflop_table.bin');
+
+    console.log('🃏 Heads-up preflop equity table generator\n');
+
+    // Load WASM module
+    const wasmPath = path.join(__dirname, 'pkg', 'wasm_equity.js');
+    if (!fs.existsSync(wasmPath)) {
+        console.error(`❌ WASM module not found at ${wasmPath}`);
+        console.error('Please run: cd wasm-equity && wasm-pack build --target nodejs --out-dir pkg -- --features wasm');
+        process.exit(1);
+    }
+
+    const wasmInit = require(wasmPath);
+    const wasmModule = wasmInit.default ? await wasmInit.default() : wasmInit;
+
+    const total = wasmModule.preflop_matchup_count();
+    console.log(`📊 ${total} canonical matchups\n`);
+
+    const chunks = [];
+    const startTime = Date.now();
+    for (let start = 0; start < total; start += CHUNK_SIZE) {
+        const end = Math.min(start + CHUNK_SIZE, total);
+        chunks.push(Buffer.from(wasmModule.generate_preflop_table_chunk(start, end)));
+
+        const elapsed = Date.now() - startTime;
+        const remaining = (elapsed / end) * (total - end);
+        console.log(
+            `  ${end}/${total} matchups, ${formatTime(elapsed)} elapsed, ~${formatTime(remaining)} remaining`
+        );
+    }
+
+    // Throws if the entries are truncated or inconsistent
+    const table = wasmModule.assemble_preflop_table(Buffer.concat(chunks));
+
+    fs.writeFileSync(outputPath, table);
+    console.log(`\n✅ Wrote ${table.length} bytes to ${outputPath}`);
+}
+
+generatePreflopTable().catch(error => {
+    console.error('❌ Table generation failed:', error);
+    process.exit(1);
+});
@@ -0,0 +1,266 @@
+// NDJSON batch mode of the CLI: one request per input line, one result per output line
+//
+// Lines are `CalculateEquityRequest` bodies (with `players`) or `CalculateOutsRequest`
+// bodies (with `hero`, `villain` and `board`), as the server receives them. Each
+// result line is the module's result object, or `{"error": "..."}` for a request the
+// server would reject. Omitted options take the server's defaults, and adaptive time
+// budgets are capped at 2 s as the server caps them. Lines are processed in chunks, in
+// parallel in native builds with the `rayon` feature, and written in input order.
+
+use std::io::{self, BufRead, Write};
+
+#[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use wasm_equity::{
+    adaptive_equity, equity_vs_random, exact_equity, monte_carlo_equity, parse_card_list, turn_outs,
+    AdaptiveLimits, Card, EquityError,
+};
+
+use crate::parse_hole;
+
+// Lines read and processed together before their results are written
+const CHUNK_LINES: usize = 256;
+
+// Server defaults (integrations/hand/equity.ts and equityRust.ts) for omitted options,
+// and the server's cap on adaptive time budgets
+const DEFAULT_MONTE_CARLO_SAMPLES: u64 = 100_000;
+const DEFAULT_ADAPTIVE_TIME_BUDGET_MS: f64 = 500.0;
+const MAX_ADAPTIVE_TIME_BUDGET_MS: f64 = 2_000.0;
+
+// `EquityOptions.mode`
+#[derive(Deserialize, Default, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum EquityMode {
+    #[default]
+    Rust,
+    MonteCarlo,
+    Adaptive,
+}
+
+// `EquityOptions` from common/src/interfaces/equityInterfaces.ts
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct EquityOptions {
+    #[serde(default)]
+    mode: EquityMode,
+    samples: Option<u64>,
+    seed: Option<u64>,
+    target_precision: Option<f64>,
+    time_budget_ms: Option<f64>,
+    #[serde(default)]
+    random_opponents: usize,
+}
+
+// `CalculateEquityRequest` from common/src/interfaces/apiInterfaces.ts
+#[derive(Deserialize)]
+struct CalculateEquityRequest {
+    players: Vec<String>,
+    #[serde(default)]
+    board: String,
+    #[serde(default)]
+    options: EquityOptions,
+    #[serde(default)]
+    dead: Vec<String>,
+}
+
+// `CalculateOutsRequest` from common/src/interfaces/apiInterfaces.ts
+#[derive(Deserialize)]
+struct CalculateOutsRequest {
+    hero: String,
+    villain: String,
+    board: String,
+}
+
+// Output line for a rejected request, like the server's `ApiErrorResponse`
+#[derive(Serialize)]
+struct ErrorLine {
+    error: String,
+}
+
+fn parse_cards(text: &str) -> Result<Vec<Card>, String> {
+    parse_card_list(text).map_err(|error| error.to_string())
+}
+
+// Adaptive limits as the server sets them: the time budget defaults to 500 ms and is
+// capped at 2 s (NaN and negative budgets are left for `adaptive_equity` to reject)
+fn adaptive_limits(options: &EquityOptions) -> AdaptiveLimits {
+    AdaptiveLimits {
+        target_precision: options.target_precision.unwrap_or(0.0),
+        time_budget_ms: options
+            .time_budget_ms
+            .map_or(DEFAULT_ADAPTIVE_TIME_BUDGET_MS, |budget| {
+                if budget > MAX_ADAPTIVE_TIME_BUDGET_MS {
+                    MAX_ADAPTIVE_TIME_BUDGET_MS
+                } else {
+                    budget
+                }
+            }),
+        max_samples: options.samples.unwrap_or(0),
+    }
+}
+
+fn calculate_equity(request: CalculateEquityRequest) -> Result<String, String> {
+    let options = &request.options;
+    if request.players.len() + options.random_opponents < 2 {
+        return Err("At least 2 players are required".to_string());
+    }
+    let players = request
+        .players
+        .iter()
+        .map(|text| parse_hole(text))
+        .collect::<Result<Vec<_>, _>>()?;
+    let board = parse_cards(&request.board)?;
+    let dead = parse_cards(&request.dead.join(" "))?;
+
+    let samples = options.samples.unwrap_or(DEFAULT_MONTE_CARLO_SAMPLES);
+    let seed = options.seed.unwrap_or(0);
+    let result = if options.random_opponents > 0 {
+        let seats: Vec<Option<[Card; 2]>> = players
+            .into_iter()
+            .map(Some)
+            .chain(std::iter::repeat_n(None, options.random_opponents))
+            .collect();
+        equity_vs_random(&seats, &board, &dead, samples, seed)
+    } else {
+        match options.mode {
+            EquityMode::Rust => exact_equity(&players, &board, &dead),
+            EquityMode::MonteCarlo => monte_carlo_equity(&players, &board, &dead, samples, seed),
+            EquityMode::Adaptive => adaptive_equity(&players, &board, &dead, &adaptive_limits(options), seed),
+        }
+    };
+    result.map(|result| crate::to_json(&result)).map_err(|error: EquityError| error.to_string())
+}
+
+fn calculate_outs(request: CalculateOutsRequest) -> Result<String, String> {
+    let hero = parse_hole(&request.hero)?;
+    let villain = parse_hole(&request.villain)?;
+    let board: [Card; 4] = parse_cards(&request.board)?
+        .try_into()
+        .map_err(|_| "Board must have exactly 4 cards (turn)".to_string())?;
+    let result = turn_outs(hero, villain, board).map_err(|error| error.to_string())?;
+    Ok(crate::to_json(&result))
+}
+
+// Dispatch one request line by its fields: `players` for equity, `hero` for outs
+fn process_line(line: &str) -> String {
+    let result = serde_json::from_str::<Value>(line)
+        .map_err(|error| format!("Invalid JSON: {}", error))
+        .and_then(|request| {
+            if request.get("players").is_some() {
+                let request = serde_json::from_value(request).map_err(|error| error.to_string())?;
+                calculate_equity(request)
+            } else if request.get("hero").is_some() {
+                let request = serde_json::from_value(request).map_err(|error| error.to_string())?;
+                calculate_outs(request)
+            } else {
+                Err("Unrecognized request: expected `players` (equity) or `hero`, `villain` and `board` (outs)"
+                    .to_string())
+            }
+        });
+    result.unwrap_or_else(|error| crate::to_json(&ErrorLine { error }))
+}
+
+fn process_chunk(lines: &[String]) -> Vec<String> {
+    #[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+    {
+        lines.par_iter().map(|line| process_line(line)).collect()
+    }
+    #[cfg(not(all(feature = "rayon", not(target_arch = "wasm32"))))]
+    {
+        lines.iter().map(|line| process_line(line)).collect()
+    }
+}
+
+fn write_chunk(lines: &[String], output: &mut impl Write) -> io::Result<()> {
+    for result in process_chunk(lines) {
+        writeln!(output, "{}", result)?;
+    }
+    output.flush()
+}
+
+/// Answer every request line of `input` on `output`, in order (blank lines are skipped)
+pub fn run(input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+    let mut chunk = Vec::with_capacity(CHUNK_LINES);
+    for line in input.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        chunk.push(line);
+        if chunk.len() == CHUNK_LINES {
+            write_chunk(&chunk, &mut output)?;
+            chunk.clear();
+        }
+    }
+    write_chunk(&chunk, &mut output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request_line(index: usize) -> String {
+        match index % 6 {
+            // Each valid equity line samples as many boards as its index, to check order
+            0 | 3 => format!(
+                r#"{{"players":["AhAd","KsKc"],"options":{{"mode":"monte_carlo","samples":{},"seed":1}}}}"#,
+                index + 1
+            ),
+            1 => r#"{"players": ["AhAd", "#.to_string(),
+            2 => r#"{"players":["AhAd","KsKc"],"options":{"mode":"fast"}}"#.to_string(),
+            4 => r#"{"players":["AhAd","AhKc"],"board":"2c 7d 9h"}"#.to_string(),
+            _ => r#"{"hero":"AhKh","villain":"QsQd","board":"2h 7h 9c 3d"}"#.to_string(),
+        }
+    }
+
+    #[test]
+    fn writes_one_result_per_line_in_input_order() {
+        let lines = 2 * CHUNK_LINES + 40;
+        let mut input = String::new();
+        for index in 0..lines {
+            input.push_str(&request_line(index));
+            input.push('\n');
+            if index % 50 == 0 {
+                input.push_str("  \n");
+            }
+        }
+
+        let mut output = Vec::new();
+        run(io::Cursor::new(input), &mut output).unwrap();
+        let results: Vec<Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(results.len(), lines);
+
+        for (index, result) in results.iter().enumerate() {
+            let error = result["error"].as_str();
+            match index % 6 {
+                0 | 3 => assert_eq!(result["samples"], index as u64 + 1),
+                1 => assert!(error.unwrap().starts_with("Invalid JSON"), "{}", result),
+                2 => assert!(error.unwrap().contains("unknown variant `fast`"), "{}", result),
+                4 => assert_eq!(error, Some("Duplicate card found: Ah")),
+                _ => assert!(result["win_outs"].is_array(), "{}", result),
+            }
+        }
+    }
+
+    #[test]
+    fn adaptive_time_budgets_are_capped_like_the_server() {
+        let budget = |time_budget_ms| {
+            let options = EquityOptions {
+                time_budget_ms,
+                ..EquityOptions::default()
+            };
+            adaptive_limits(&options).time_budget_ms
+        };
+        assert_eq!(budget(None), DEFAULT_ADAPTIVE_TIME_BUDGET_MS);
+        assert_eq!(budget(Some(0.0)), 0.0);
+        assert_eq!(budget(Some(750.0)), 750.0);
+        assert_eq!(budget(Some(60_000.0)), MAX_ADAPTIVE_TIME_BUDGET_MS);
+        assert!(budget(Some(f64::NAN)).is_nan());
+    }
+}
@@ -0,0 +1,385 @@
+// 64-bit card sets
+//
+// One bit per card of the 52-card deck, rank-major: bit (rank - 2) * 4 + suit, so
+// iterating a set visits cards in deck order (2c, 2d, 2h, 2s, 3c, ... As). Card
+// notation is a rank character (2-9, T, J, Q, K, A) followed by a suit (c, d, h, s).
+
+use std::fmt;
+use std::ops::{BitAnd, BitOr, Not, Sub};
+
+use crate::error::EquityError;
+use crate::Card;
+
+// Rank characters in notation, indexed by rank - 2 (2-9, T, J, Q, K, A)
+pub const RANK_CHARS: [char; 13] = ['2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A'];
+pub const SUIT_CHARS: [char; 4] = ['c', 'd', 'h', 's'];
+
+pub fn parse_rank(c: char) -> Option<u8> {
+    RANK_CHARS
+        .iter()
+        .position(|&r| r == c.to_ascii_uppercase())
+        .map(|idx| idx as u8 + 2)
+}
+
+pub fn parse_suit(c: char) -> Option<u8> {
+    SUIT_CHARS.iter().position(|&s| s == c).map(|idx| idx as u8)
+}
+
+pub fn rank_char(rank: u8) -> char {
+    RANK_CHARS[(rank - 2) as usize]
+}
+
+/// Parse cards in order from notation ("AhKd", "Ah Kd, 7c") or the server's numeric
+/// ranks ("14h 13d 7c", "10s"), rejecting duplicates
+pub fn parse_card_list(text: &str) -> Result<Vec<Card>, EquityError> {
+    let chars: Vec<char> = text
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != ',')
+        .collect();
+
+    let mut cards = Vec::new();
+    let mut seen = CardSet::EMPTY;
+    let mut pos = 0;
+    while pos < chars.len() {
+        // A rank is one character, or "10"-"14" in numeric notation
+        let rank_len = match chars[pos..] {
+            ['1', digit, ..] if digit.is_ascii_digit() => 2,
+            _ => 1,
+        };
+        let token: String = chars[pos..(pos + rank_len + 1).min(chars.len())].iter().collect();
+        let rank = if rank_len == 2 {
+            token[..2].parse().ok()
+        } else {
+            parse_rank(chars[pos])
+        };
+        let suit = chars.get(pos + rank_len).copied().and_then(parse_suit);
+        let card = match (rank, suit) {
+            (Some(rank), Some(suit)) if (2..=14).contains(&rank) => Card { rank, suit },
+            _ => return Err(EquityError::InvalidNotation(token)),
+        };
+        if !seen.insert(card) {
+            return Err(EquityError::DuplicateCard(card));
+        }
+        cards.push(card);
+        pos += rank_len + 1;
+    }
+    Ok(cards)
+}
+
+const DECK_BITS: u64 = (1 << 52) - 1;
+
+/// A set of cards as a 52-bit mask
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CardSet(u64);
+
+impl CardSet {
+    pub const EMPTY: CardSet = CardSet(0);
+    pub const FULL_DECK: CardSet = CardSet(DECK_BITS);
+
+    /// Bit index of a valid card (0-51)
+    #[inline(always)]
+    pub fn index(card: Card) -> u32 {
+        (card.rank as u32 - 2) * 4 + card.suit as u32
+    }
+
+    /// Card at a bit index (0-51)
+    #[inline(always)]
+    pub fn card_at(index: u32) -> Card {
+        Card {
+            rank: (index / 4) as u8 + 2,
+            suit: (index % 4) as u8,
+        }
+    }
+
+    #[inline(always)]
+    pub fn bits(self) -> u64 {
+        self.0
+    }
+
+    /// Set of valid cards (duplicates collapse)
+    pub fn from_cards(cards: &[Card]) -> Self {
+        cards.iter().fold(CardSet::EMPTY, |set, &card| set.with(card))
+    }
+
+    /// A card with rank 2-14 and suit 0-3, or the reason it is out of range
+    pub fn validate(card: Card) -> Result<Card, EquityError> {
+        if !(2..=14).contains(&card.rank) {
+            return Err(EquityError::InvalidRank(card));
+        }
+        if card.suit > 3 {
+            return Err(EquityError::InvalidSuit(card));
+        }
+        Ok(card)
+    }
+
+    /// Set of cards, rejecting out-of-range and duplicate cards
+    pub fn try_from_cards(cards: &[Card]) -> Result<Self, EquityError> {
+        let mut set = CardSet::EMPTY;
+        for &card in cards {
+            if !set.insert(Self::validate(card)?) {
+                return Err(EquityError::DuplicateCard(card));
+            }
+        }
+        Ok(set)
+    }
+
+    /// Parse a set of cards in any notation `parse_card_list` accepts, e.g. "AhKd7c"
+    pub fn parse(text: &str) -> Result<Self, EquityError> {
+        Ok(Self::from_cards(&parse_card_list(text)?))
+    }
+
+    #[inline(always)]
+    pub fn contains(self, card: Card) -> bool {
+        self.0 & (1 << Self::index(card)) != 0
+    }
+
+    /// Add a card; returns false if it was already present
+    #[inline(always)]
+    pub fn insert(&mut self, card: Card) -> bool {
+        let bit = 1 << Self::index(card);
+        let added = self.0 & bit == 0;
+        self.0 |= bit;
+        added
+    }
+
+    #[inline(always)]
+    pub fn with(self, card: Card) -> Self {
+        CardSet(self.0 | 1 << Self::index(card))
+    }
+
+    #[inline(always)]
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    #[inline(always)]
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    #[inline(always)]
+    pub fn union(self, other: CardSet) -> Self {
+        CardSet(self.0 | other.0)
+    }
+
+    #[inline(always)]
+    pub fn intersection(self, other: CardSet) -> Self {
+        CardSet(self.0 & other.0)
+    }
+
+    #[inline(always)]
+    pub fn difference(self, other: CardSet) -> Self {
+        CardSet(self.0 & !other.0)
+    }
+
+    /// Cards of the deck not in this set
+    #[inline(always)]
+    pub fn complement(self) -> Self {
+        CardSet(!self.0 & DECK_BITS)
+    }
+
+    #[inline(always)]
+    pub fn is_disjoint(self, other: CardSet) -> bool {
+        self.0 & other.0 == 0
+    }
+
+    /// Cards in deck order
+    pub fn iter(self) -> CardSetIter {
+        CardSetIter(self.0)
+    }
+
+    /// Cards in deck order, collected
+    pub fn to_vec(self) -> Vec<Card> {
+        self.iter().collect()
+    }
+}
+
+impl BitOr for CardSet {
+    type Output = CardSet;
+
+    fn bitor(self, other: CardSet) -> CardSet {
+        self.union(other)
+    }
+}
+
+impl BitAnd for CardSet {
+    type Output = CardSet;
+
+    fn bitand(self, other: CardSet) -> CardSet {
+        self.intersection(other)
+    }
+}
+
+impl Sub for CardSet {
+    type Output = CardSet;
+
+    fn sub(self, other: CardSet) -> CardSet {
+        self.difference(other)
+    }
+}
+
+impl Not for CardSet {
+    type Output = CardSet;
+
+    fn not(self) -> CardSet {
+        self.complement()
+    }
+}
+
+impl IntoIterator for CardSet {
+    type Item = Card;
+    type IntoIter = CardSetIter;
+
+    fn into_iter(self) -> CardSetIter {
+        self.iter()
+    }
+}
+
+impl FromIterator<Card> for CardSet {
+    fn from_iter<I: IntoIterator<Item = Card>>(cards: I) -> Self {
+        cards.into_iter().fold(CardSet::EMPTY, |set, card| set.with(card))
+    }
+}
+
+/// Iterator over a card set, lowest bit first
+pub struct CardSetIter(u64);
+
+impl Iterator for CardSetIter {
+    type Item = Card;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Card> {
+        if self.0 == 0 {
+            return None;
+        }
+        let index = self.0.trailing_zeros();
+        self.0 &= self.0 - 1;
+        Some(CardSet::card_at(index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for CardSetIter {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Card> {
+        if self.0 == 0 {
+            return None;
+        }
+        let index = 63 - self.0.leading_zeros();
+        self.0 &= !(1 << index);
+        Some(CardSet::card_at(index))
+    }
+}
+
+impl ExactSizeIterator for CardSetIter {}
+
+impl fmt::Display for Card {
+    /// Card notation, e.g. "Ah"; a rank or suit out of range is printed as its number
+    /// ("15h", "A?7") so invalid inputs can be reported
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.rank as usize).checked_sub(2).and_then(|idx| RANK_CHARS.get(idx)) {
+            Some(rank) => write!(f, "{}", rank)?,
+            None => write!(f, "{}", self.rank)?,
+        }
+        match SUIT_CHARS.get(self.suit as usize) {
+            Some(suit) => write!(f, "{}", suit),
+            None => write!(f, "?{}", self.suit),
+        }
+    }
+}
+
+impl fmt::Display for CardSet {
+    /// Cards in deck order, e.g. "7cKdAh"
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for card in self.iter() {
+            write!(f, "{}", card)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card(rank: u8, suit: u8) -> Card {
+        Card { rank, suit }
+    }
+
+    #[test]
+    fn parses_notation_and_numeric_ranks() {
+        let expected = vec![card(14, 2), card(13, 1), card(7, 0)];
+        assert_eq!(parse_card_list("AhKd7c").unwrap(), expected);
+        assert_eq!(parse_card_list("ah kd, 7c").unwrap(), expected);
+        assert_eq!(parse_card_list("14h 13d 7c").unwrap(), expected);
+        assert_eq!(parse_card_list("14hKd7c").unwrap(), expected);
+        assert_eq!(parse_card_list("10s 11s 12s").unwrap(), [card(10, 3), card(11, 3), card(12, 3)]);
+        assert_eq!(parse_card_list("").unwrap(), []);
+    }
+
+    #[test]
+    fn rejects_bad_cards_and_duplicates() {
+        let invalid = [("Ah Xd", "Xd"), ("Ahx", "x"), ("A", "A"), ("15h", "15h"), ("1h", "1h"), ("Ah Kz", "Kz")];
+        for (text, token) in invalid {
+            assert_eq!(parse_card_list(text), Err(EquityError::InvalidNotation(token.to_string())), "{}", text);
+        }
+        assert_eq!(parse_card_list("Ah Kd 14h"), Err(EquityError::DuplicateCard(card(14, 2))));
+        assert_eq!(CardSet::parse("AhAh"), Err(EquityError::DuplicateCard(card(14, 2))));
+        assert_eq!(CardSet::parse("Ah K"), Err(EquityError::InvalidNotation("K".to_string())));
+    }
+
+    #[test]
+    fn set_operations() {
+        let hand = CardSet::parse("Ah Kd").unwrap();
+        let board = CardSet::parse("Kd 7c 2s").unwrap();
+        assert_eq!(hand.len(), 2);
+        assert!(!hand.is_empty() && CardSet::EMPTY.is_empty());
+        assert!(hand.contains(card(14, 2)) && !hand.contains(card(14, 3)));
+        assert_eq!(hand | board, CardSet::parse("2s 7c Kd Ah").unwrap());
+        assert_eq!(hand & board, CardSet::parse("Kd").unwrap());
+        assert_eq!(board - hand, CardSet::parse("7c 2s").unwrap());
+        assert!(!hand.is_disjoint(board) && hand.is_disjoint(board - hand));
+        assert_eq!((!hand).len(), 50);
+        assert_eq!(!!hand, hand);
+        assert_eq!(!CardSet::EMPTY, CardSet::FULL_DECK);
+
+        let mut set = CardSet::EMPTY;
+        assert!(set.insert(card(2, 0)));
+        assert!(!set.insert(card(2, 0)));
+        assert_eq!(set.with(card(3, 1)).to_vec(), [card(2, 0), card(3, 1)]);
+    }
+
+    #[test]
+    fn iterates_in_deck_order() {
+        let deck = CardSet::FULL_DECK.to_vec();
+        assert_eq!(deck.len(), 52);
+        for (index, &card) in deck.iter().enumerate() {
+            assert_eq!(CardSet::index(card), index as u32);
+            assert_eq!(CardSet::card_at(index as u32), card);
+        }
+        assert_eq!(deck.iter().copied().collect::<CardSet>(), CardSet::FULL_DECK);
+
+        let set = CardSet::parse("As 2c Td").unwrap();
+        assert_eq!(set.to_string(), "2cTdAs");
+        assert_eq!(set.iter().rev().collect::<Vec<_>>(), [card(14, 3), card(10, 1), card(2, 0)]);
+        assert_eq!(set.iter().len(), 3);
+    }
+
+    #[test]
+    fn validates_card_ranges() {
+        assert_eq!(CardSet::validate(card(2, 3)), Ok(card(2, 3)));
+        assert_eq!(CardSet::validate(card(15, 0)), Err(EquityError::InvalidRank(card(15, 0))));
+        assert_eq!(CardSet::validate(card(1, 0)), Err(EquityError::InvalidRank(card(1, 0))));
+        assert_eq!(CardSet::validate(card(9, 4)), Err(EquityError::InvalidSuit(card(9, 4))));
+        assert_eq!(
+            CardSet::try_from_cards(&[card(9, 1), card(9, 1)]),
+            Err(EquityError::DuplicateCard(card(9, 1)))
+        );
+        assert_eq!(card(15, 2).to_string(), "15h");
+        assert_eq!(card(14, 7).to_string(), "A?7");
+    }
+}
@@ -0,0 +1,233 @@
+// Human-readable hand descriptions from message catalogs
+//
+// A description is built from a hand value (category + tiebreak): the category's
+// template names the made hand ("Full house, {0s} full of {1s}"), then pairs, trips and
+// quads add their first kicker and wheels are marked. High-card hands name their top
+// two cards instead ("Ace-King high"). Every word comes from a
+// `MessageCatalog`, so a locale is added by registering a catalog with its own
+// templates and rank names. English is built in and is the fallback for unknown locales.
+//
+// Template placeholders: `{0}`, `{1}`, ... are the tiebreak ranks by position (`{0s}`
+// the plural name), `{hand}` and `{kicker}` are used by `with_kicker` and `wheel`.
+
+use std::sync::{PoisonError, RwLock};
+
+use serde::Deserialize;
+#[cfg(feature = "wasm")]
+use tsify::Tsify;
+
+use crate::error::EquityError;
+use crate::HandValue;
+
+// Tiebreak ranks each category carries (see `encode_hand_rank`)
+const TIEBREAK_LENGTHS: [usize; 10] = [5, 4, 3, 3, 1, 5, 2, 2, 1, 0];
+
+// Tiebreak position of the kicker named in the description, for made hands with kickers
+const KICKER_POSITIONS: [Option<usize>; 10] =
+    [None, Some(1), Some(2), Some(1), None, None, None, Some(1), None, None];
+
+/// Words and templates for hand descriptions in one locale
+///
+/// `ranks` and `rank_plurals` name the ranks Two to Ace, `categories` holds one
+/// template per category (0 = high card ... 9 = royal flush), `with_kicker` appends a
+/// kicker to a description and `wheel` marks a five-high straight.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "wasm", derive(Tsify))]
+pub struct MessageCatalog {
+    pub locale: String,
+    pub ranks: [String; 13],
+    pub rank_plurals: [String; 13],
+    pub categories: [String; 10],
+    pub with_kicker: String,
+    pub wheel: String,
+}
+
+impl MessageCatalog {
+    /// The built-in English catalog
+    pub fn english() -> Self {
+        MessageCatalog {
+            locale: "en".to_string(),
+            ranks: [
+                "Two", "Three", "Four", "Five", "Six", "Seven", "Eight", "Nine", "Ten", "Jack", "Queen", "King", "Ace",
+            ]
+            .map(String::from),
+            rank_plurals: [
+                "Twos", "Threes", "Fours", "Fives", "Sixes", "Sevens", "Eights", "Nines", "Tens", "Jacks", "Queens",
+                "Kings", "Aces",
+            ]
+            .map(String::from),
+            categories: [
+                "{0}-{1} high",
+                "Pair of {0s}",
+                "Two pair, {0s} and {1s}",
+                "Three of a kind, {0s}",
+                "Straight, {0} high",
+                "Flush, {0} high",
+                "Full house, {0s} full of {1s}",
+                "Four of a kind, {0s}",
+                "Straight flush, {0} high",
+                "Royal flush",
+            ]
+            .map(String::from),
+            with_kicker: "{hand}, {kicker} kicker".to_string(),
+            wheel: "{hand} (wheel)".to_string(),
+        }
+    }
+
+    fn rank_name(&self, rank: u8) -> &str {
+        &self.ranks[rank as usize - 2]
+    }
+
+    fn rank_plural(&self, rank: u8) -> &str {
+        &self.rank_plurals[rank as usize - 2]
+    }
+
+    // Describe a checked hand value
+    fn describe(&self, value: &HandValue) -> String {
+        let category = value.category as usize;
+        let mut text = self.categories[category].clone();
+        for (position, &rank) in value.tiebreak.iter().enumerate() {
+            text = text
+                .replace(&format!("{{{}s}}", position), self.rank_plural(rank))
+                .replace(&format!("{{{}}}", position), self.rank_name(rank));
+        }
+
+        if let Some(position) = KICKER_POSITIONS[category] {
+            text = self
+                .with_kicker
+                .replace("{hand}", &text)
+                .replace("{kicker}", self.rank_name(value.tiebreak[position]));
+        }
+        // Straights and straight flushes to the five
+        if matches!(category, 4 | 8) && value.tiebreak[0] == 5 {
+            text = self.wheel.replace("{hand}", &text);
+        }
+        text
+    }
+}
+
+// Catalogs added with `register_catalog`, searched before the built-in English one
+static CATALOGS: RwLock<Vec<MessageCatalog>> = RwLock::new(Vec::new());
+
+/// Add a catalog for its locale, replacing any registered catalog for the same locale
+///
+/// Registering "en" overrides the built-in English wording.
+pub fn register_catalog(catalog: MessageCatalog) -> Result<(), EquityError> {
+    if catalog.locale.trim().is_empty() {
+        return Err(EquityError::InvalidArgument("A catalog needs a locale".to_string()));
+    }
+    let mut catalogs = CATALOGS.write().unwrap_or_else(PoisonError::into_inner);
+    catalogs.retain(|registered| !registered.locale.eq_ignore_ascii_case(&catalog.locale));
+    catalogs.push(catalog);
+    Ok(())
+}
+
+// Reject categories above 9 and tiebreaks that do not fit the category
+fn check_hand_value(value: &HandValue) -> Result<(), EquityError> {
+    let invalid = || {
+        EquityError::InvalidArgument(format!(
+            "Invalid hand rank: category {}, tiebreak {:?}",
+            value.category, value.tiebreak
+        ))
+    };
+    let expected = *TIEBREAK_LENGTHS.get(value.category as usize).ok_or_else(invalid)?;
+    if value.tiebreak.len() != expected || value.tiebreak.iter().any(|&rank| !(2..=14).contains(&rank)) {
+        return Err(invalid());
+    }
+    Ok(())
+}
+
+/// Describe a hand value in words, e.g. "Full house, Aces full of Kings"
+///
+/// Uses the catalog registered for `locale`, then the one for its language ("de" for
+/// "de-AT"), then English. Exported to wasm as `describe_hand`.
+pub fn describe_hand(value: &HandValue, locale: &str) -> Result<String, EquityError> {
+    check_hand_value(value)?;
+
+    let catalogs = CATALOGS.read().unwrap_or_else(PoisonError::into_inner);
+    let language = locale.split(['-', '_']).next().unwrap_or(locale);
+    let catalog = catalogs
+        .iter()
+        .find(|catalog| catalog.locale.eq_ignore_ascii_case(locale))
+        .or_else(|| catalogs.iter().find(|catalog| catalog.locale.eq_ignore_ascii_case(language)))
+        .or_else(|| catalogs.iter().find(|catalog| catalog.locale == "en"));
+    Ok(match catalog {
+        Some(catalog) => catalog.describe(value),
+        None => MessageCatalog::english().describe(value),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Catalogs are global and tests run in parallel: each test registers its own locales
+
+    fn value(category: u8, tiebreak: &[u8]) -> HandValue {
+        HandValue {
+            category,
+            tiebreak: tiebreak.to_vec(),
+            best_five: None,
+        }
+    }
+
+    fn english(category: u8, tiebreak: &[u8]) -> String {
+        describe_hand(&value(category, tiebreak), "en").unwrap()
+    }
+
+    #[test]
+    fn describes_every_category_in_english() {
+        assert_eq!(english(0, &[14, 13, 9, 7, 3]), "Ace-King high");
+        assert_eq!(english(1, &[12, 14, 7, 5]), "Pair of Queens, Ace kicker");
+        assert_eq!(english(2, &[11, 10, 4]), "Two pair, Jacks and Tens, Four kicker");
+        assert_eq!(english(3, &[9, 5, 2]), "Three of a kind, Nines, Five kicker");
+        assert_eq!(english(4, &[10]), "Straight, Ten high");
+        assert_eq!(english(4, &[5]), "Straight, Five high (wheel)");
+        assert_eq!(english(5, &[14, 9, 8, 6, 4]), "Flush, Ace high");
+        assert_eq!(english(6, &[14, 13]), "Full house, Aces full of Kings");
+        assert_eq!(english(7, &[8, 2]), "Four of a kind, Eights, Two kicker");
+        assert_eq!(english(8, &[5]), "Straight flush, Five high (wheel)");
+        assert_eq!(english(9, &[]), "Royal flush");
+    }
+
+    #[test]
+    fn rejects_values_that_do_not_fit_the_category() {
+        for (category, tiebreak) in [(10, &[][..]), (6, &[14][..]), (1, &[12, 14, 7, 15][..]), (4, &[1][..])] {
+            assert!(describe_hand(&value(category, tiebreak), "en").is_err());
+        }
+    }
+
+    #[test]
+    fn falls_back_from_locale_to_language_to_english() {
+        let mut language = MessageCatalog::english();
+        language.locale = "xx".to_string();
+        language.with_kicker = "{hand} / {kicker}".to_string();
+        register_catalog(language).unwrap();
+        let mut regional = MessageCatalog::english();
+        regional.locale = "xx-YY".to_string();
+        regional.ranks[12] = "Ace (YY)".to_string();
+        register_catalog(regional).unwrap();
+
+        let pair = value(1, &[12, 14, 7, 5]);
+        assert_eq!(describe_hand(&pair, "xx-YY").unwrap(), "Pair of Queens, Ace (YY) kicker");
+        assert_eq!(describe_hand(&pair, "XX-yy").unwrap(), "Pair of Queens, Ace (YY) kicker");
+        assert_eq!(describe_hand(&pair, "xx-ZZ").unwrap(), "Pair of Queens / Ace");
+        assert_eq!(describe_hand(&pair, "xx_ZZ").unwrap(), "Pair of Queens / Ace");
+        assert_eq!(describe_hand(&pair, "xx").unwrap(), "Pair of Queens / Ace");
+        assert_eq!(describe_hand(&pair, "zz-XX").unwrap(), "Pair of Queens, Ace kicker");
+        assert_eq!(describe_hand(&pair, "").unwrap(), "Pair of Queens, Ace kicker");
+    }
+
+    #[test]
+    fn registering_a_locale_again_replaces_it() {
+        let mut catalog = MessageCatalog::english();
+        catalog.locale = "qq".to_string();
+        register_catalog(catalog.clone()).unwrap();
+        catalog.categories[9] = "Royal".to_string();
+        register_catalog(catalog.clone()).unwrap();
+        assert_eq!(describe_hand(&value(9, &[]), "qq").unwrap(), "Royal");
+
+        catalog.locale = " ".to_string();
+        assert!(register_catalog(catalog).is_err());
+    }
+}
@@ -0,0 +1,67 @@
+// Typed input errors
+//
+// Core functions return `EquityError`; the wasm exports turn it into a thrown JS
+// `Error` whose message is the `Display` text (wasm-bindgen converts any
+// `std::error::Error` into a `JsError`).
+
+use std::fmt;
+
+use crate::Card;
+
+/// Why an input was rejected
+#[derive(Debug, Clone, PartialEq)]
+pub enum EquityError {
+    /// A card array has the wrong length, e.g. a hand without exactly 2 cards
+    BadLength {
+        cards: &'static str,
+        expected: &'static str,
+    },
+    /// A rank outside 2-14
+    InvalidRank(Card),
+    /// A suit outside 0-3
+    InvalidSuit(Card),
+    /// Card notation that does not parse, e.g. "Zx"
+    InvalidNotation(String),
+    /// The same card appears twice among the hands, board and dead cards
+    DuplicateCard(Card),
+    /// A calculation without players
+    NoPlayers,
+    /// More players than a calculation supports
+    TooManyPlayers { players: usize, max: usize },
+    /// Fewer undealt cards than the board (and any random hands) need
+    InsufficientDeck { needed: usize, available: usize },
+    /// A hand range that does not parse or has no usable combos
+    InvalidRange(String),
+    /// Preflop table bytes that do not decode, or a lookup the table cannot answer
+    PreflopTable(String),
+    /// Any other argument out of range (sampling limits, backend names, ...)
+    InvalidArgument(String),
+}
+
+impl fmt::Display for EquityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EquityError::BadLength { cards, expected } => write!(f, "{} must have {}", cards, expected),
+            EquityError::InvalidRank(card) => {
+                write!(f, "Invalid card: {} (rank must be 2-14)", card)
+            }
+            EquityError::InvalidSuit(card) => {
+                write!(f, "Invalid card: {} (suit must be 0-3)", card)
+            }
+            EquityError::InvalidNotation(text) => write!(f, "Invalid card: {}", text),
+            EquityError::DuplicateCard(card) => write!(f, "Duplicate card found: {}", card),
+            EquityError::NoPlayers => write!(f, "At least one player is required"),
+            EquityError::TooManyPlayers { players, max } => {
+                write!(f, "Too many players: {} (at most {})", players, max)
+            }
+            EquityError::InsufficientDeck { needed, available } => {
+                write!(f, "Not enough cards in deck: need {}, have {}", needed, available)
+            }
+            EquityError::InvalidRange(message)
+            | EquityError::PreflopTable(message)
+            | EquityError::InvalidArgument(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for EquityError {}
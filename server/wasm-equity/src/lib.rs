@@ -1,12 +1,65 @@
-use wasm_bindgen::prelude::*;
+//! Texas Hold'em equity engine: cards, the hand evaluator, exact and sampled equity,
+//! ranges and turn outs.
+//!
+//! The core is plain Rust with a public API. The Node server uses it through the
+//! wasm-bindgen exports in `wasm.rs` (the `wasm` feature); native builds add the OS
+//! clock (`std`), rayon threads (`rayon`) and the `wasm-equity` CLI (`cli`).
+
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+#[cfg(all(feature = "simd", target_arch = "wasm32", not(target_feature = "simd128")))]
+compile_error!("the `simd` feature needs simd128: build with RUSTFLAGS=\"-C target-feature=+simd128\"");
+
+mod card_set;
+mod describe;
+mod error;
+mod preflop_table;
+mod range;
+mod range_equity;
+mod results;
+#[cfg(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128"))]
+mod simd;
+mod symmetry;
+mod table_evaluator;
+#[cfg(feature = "wasm")]
+mod wasm;
+
+use preflop_table::PreflopTable;
+use range::Range;
+use range_equity::range_equity;
+use symmetry::SuitSymmetry;
+
+pub use card_set::{parse_card_list, rank_char, CardSet, CardSetIter};
+pub use describe::{describe_hand, register_catalog, MessageCatalog};
+pub use error::EquityError;
+pub use results::{
+    BoardCounts, EquityResult, EvaluatorBackendInfo, HandValue, HeroComboEquity, NormalizedRange, OutCard, OutsResult,
+    OutsSuppressionReason, PreflopTableInfo, RangeEquityResult, StopReason,
+};
 
 // Card representation: rank 2-14 (Ace=14), suit 0-3 (c, d, h, s)
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
-struct Card {
+pub struct Card {
     rank: u8,  // 2-14 (Ace=14)
     suit: u8,  // 0-3 (c=0, d=1, h=2, s=3)
 }
 
+impl Card {
+    /// A card from rank 2-14 and suit 0-3, or the reason it is out of range
+    pub fn new(rank: u8, suit: u8) -> Result<Card, EquityError> {
+        CardSet::validate(Card { rank, suit })
+    }
+
+    pub fn rank(self) -> u8 {
+        self.rank
+    }
+
+    pub fn suit(self) -> u8 {
+        self.suit
+    }
+}
+
 // Hand rank encoded as u64 for maximum performance:
 // - Bits 56-63: category (8 bits)
 // - Bits 48-55: tiebreak[0] (8 bits)
@@ -26,25 +79,58 @@ fn encode_hand_rank(category: u8, tiebreak: &[u8]) -> HandRank {
     rank
 }
 
+// Rank and suit bitmasks of a set of cards, built incrementally so the board's
+// masks are computed once and each player's hole cards are folded in cheaply
+#[derive(Copy, Clone, Debug)]
+struct CardMasks {
+    rank_bits: u16,
+    suit_bits: [u16; 4], // One bitset per suit
+    rank_counts: [u8; 15], // Index 0 unused, 2-14 used
+}
+
+impl CardMasks {
+    const EMPTY: CardMasks = CardMasks {
+        rank_bits: 0,
+        suit_bits: [0; 4],
+        rank_counts: [0; 15],
+    };
+
+    fn from_cards(cards: &[Card]) -> Self {
+        let mut masks = CardMasks::EMPTY;
+        for &card in cards {
+            masks.add(card);
+        }
+        masks
+    }
+
+    #[inline(always)]
+    fn add(&mut self, card: Card) {
+        self.rank_bits |= 1u16 << card.rank;
+        self.suit_bits[card.suit as usize] |= 1u16 << card.rank;
+        self.rank_counts[card.rank as usize] += 1;
+    }
+
+    // Board masks plus one player's hole cards
+    #[inline(always)]
+    fn with_hole(&self, hole: &[Card; 2]) -> Self {
+        let mut masks = *self;
+        masks.add(hole[0]);
+        masks.add(hole[1]);
+        masks
+    }
+}
+
 // Direct 7-card evaluator - avoids 21×5-card evaluation loop
 // Uses rank/suit bitmasks and direct computation
 #[inline(always)]
 fn evaluate_7_card_hand(hole: &[Card; 2], board: &[Card; 5]) -> HandRank {
-    let all_cards = [
-        hole[0], hole[1],
-        board[0], board[1], board[2], board[3], board[4],
-    ];
-    
-    // Build rank and suit bitmasks for fast lookup
-    let mut rank_bits = 0u16;
-    let mut suit_bits = [0u16; 4]; // One bitset per suit
-    let mut rank_counts = [0u8; 15]; // Index 0 unused, 2-14 used
-    
-    for card in &all_cards {
-        rank_bits |= 1u16 << card.rank;
-        suit_bits[card.suit as usize] |= 1u16 << card.rank;
-        rank_counts[card.rank as usize] += 1;
-    }
+    evaluate_7_card_masks(&CardMasks::from_cards(board).with_hole(hole))
+}
+
+// Evaluate the masks of 5-7 cards (the best five-card hand among them)
+#[inline(always)]
+fn evaluate_7_card_masks(masks: &CardMasks) -> HandRank {
+    let CardMasks { rank_bits, suit_bits, rank_counts } = *masks;
     
     // Check for flush (5+ cards of same suit)
     let mut flush_suit: Option<u8> = None;
@@ -75,7 +161,7 @@ fn evaluate_7_card_hand(hole: &[Card; 2], board: &[Card; 5]) -> HandRank {
         }
         
         // Check for normal straight (5 consecutive) - find highest
-        for start in (0..=(idx as usize).saturating_sub(5)).rev() {
+        for start in (0..=idx.saturating_sub(5)).rev() {
             if unique_ranks[start + 4] - unique_ranks[start] == 4 {
                 return Some(unique_ranks[start + 4]);
             }
@@ -131,8 +217,8 @@ fn evaluate_7_card_hand(hole: &[Card; 2], board: &[Card; 5]) -> HandRank {
     }
     
     // Straight Flush - use straight_flush_high (computed from flush suit only)
-    if straight_flush_high.is_some() {
-        return encode_hand_rank(8, &[straight_flush_high.unwrap()]);
+    if let Some(high) = straight_flush_high {
+        return encode_hand_rank(8, &[high]);
     }
     
     // Four of a Kind
@@ -181,8 +267,8 @@ fn evaluate_7_card_hand(hole: &[Card; 2], board: &[Card; 5]) -> HandRank {
     }
     
     // Straight
-    if straight_high.is_some() {
-        return encode_hand_rank(4, &[straight_high.unwrap()]);
+    if let Some(high) = straight_high {
+        return encode_hand_rank(4, &[high]);
     }
     
     // Three of a Kind
@@ -251,157 +337,946 @@ fn evaluate_7_card_hand(hole: &[Card; 2], board: &[Card; 5]) -> HandRank {
     encode_hand_rank(0, &high_ranks)
 }
 
-/// Calculate preflop equity using exact enumeration
-/// 
-/// Input format (all arrays flattened):
-/// - player_ranks: array of ranks for all player cards (2 cards per player)
-/// - player_suits: array of suits for all player cards (0=c, 1=d, 2=h, 3=s)
-/// - deck_ranks: array of ranks for remaining deck cards
-/// - deck_suits: array of suits for remaining deck cards
-/// - num_players: number of players
-/// - missing: number of cards missing from board (5 for preflop)
-/// 
-/// Returns a JSON string with equity results: {"win":[0.5,0.5],"tie":[0,0],"lose":[0.5,0.5],"samples":1712304}
-#[wasm_bindgen]
-pub fn calculate_preflop_equity(
-    player_ranks: &[u8],
-    player_suits: &[u8],
-    deck_ranks: &[u8],
-    deck_suits: &[u8],
-    num_players: usize,
-    _missing: usize,  // Unused: hardcoded to 5 for preflop optimization
-) -> String {
-    // Parse players (each player has 2 cards) - use fixed-size arrays
-    let mut players: Vec<[Card; 2]> = Vec::with_capacity(num_players);
-    
-    for i in 0..num_players {
-        let card1_idx = i * 2;
-        let card2_idx = i * 2 + 1;
-        
-        players.push([
-            Card {
-                rank: player_ranks[card1_idx],
-                suit: player_suits[card1_idx],
-            },
-            Card {
-                rank: player_ranks[card2_idx],
-                suit: player_suits[card2_idx],
-            },
-        ]);
+// Split an encoded hand rank into its category and tiebreak ranks
+fn hand_value(rank: HandRank) -> HandValue {
+    HandValue {
+        category: (rank >> 56) as u8,
+        tiebreak: (0..5)
+            .map(|i| (rank >> (48 - i * 8)) as u8)
+            .take_while(|&tiebreak_rank| tiebreak_rank != 0)
+            .collect(),
+        best_five: None,
     }
-    
-    // Parse remaining deck - use Vec since size varies
-    let mut remaining_deck: Vec<Card> = Vec::with_capacity(deck_ranks.len());
-    for i in 0..deck_ranks.len() {
-        remaining_deck.push(Card {
-            rank: deck_ranks[i],
-            suit: deck_suits[i],
+}
+
+/// Category and tiebreak ranks of the best five-card hand among 5-7 distinct cards
+///
+/// Exported to wasm as `evaluate_hand`.
+pub fn evaluate_hand(cards: &[Card]) -> Result<HandValue, EquityError> {
+    if !(5..=7).contains(&cards.len()) {
+        return Err(EquityError::BadLength {
+            cards: "A hand",
+            expected: "5 to 7 cards",
         });
     }
-    
-    // Calculate equity - use integer counters to avoid f64 math in hot loop
-    // Use fixed-point arithmetic with multiplier for ties
-    const TIE_MULTIPLIER: u64 = 1_000_000;
-    
-    // Pre-compute tie fraction lookup table (1/n * TIE_MULTIPLIER for n = 2..9)
-    let tie_fractions_lut: [u64; 10] = [
-        0,
-        0,
-        TIE_MULTIPLIER / 2,  // 1/2
-        TIE_MULTIPLIER / 3,  // 1/3
-        TIE_MULTIPLIER / 4,  // 1/4
-        TIE_MULTIPLIER / 5,  // 1/5
-        TIE_MULTIPLIER / 6,  // 1/6
-        TIE_MULTIPLIER / 7,  // 1/7
-        TIE_MULTIPLIER / 8,  // 1/8
-        TIE_MULTIPLIER / 9,  // 1/9
-    ];
-    
-    let mut wins = vec![0u64; num_players];
-    let mut ties = vec![0u64; num_players];  // Stored as fixed-point (multiply by TIE_MULTIPLIER)
-    let mut total_combos = 0u64;
-    
-    // Pre-allocate all arrays outside the hot loop to avoid per-combo allocations
-    let mut complete_board = [Card { rank: 0, suit: 0 }; 5];
-    let mut player_ranks_eval = vec![0u64; num_players];
-    // Use fixed-size array for winners (max 9 players typically, but use Vec with capacity)
-    let mut winners = vec![0usize; num_players];
-    
-    // Specialized preflop enumeration: 5 nested loops for k=5 (missing=5)
-    // This eliminates recursive closure overhead and deck_indices allocation
-    let deck_len = remaining_deck.len();
-    
-    // 5 nested loops to enumerate all C(deck_len, 5) combinations
-    for i0 in 0..deck_len {
-        for i1 in (i0 + 1)..deck_len {
-            for i2 in (i1 + 1)..deck_len {
-                for i3 in (i2 + 1)..deck_len {
-                    for i4 in (i3 + 1)..deck_len {
-                        // Build complete board directly
-                        complete_board[0] = remaining_deck[i0];
-                        complete_board[1] = remaining_deck[i1];
-                        complete_board[2] = remaining_deck[i2];
-                        complete_board[3] = remaining_deck[i3];
-                        complete_board[4] = remaining_deck[i4];
-                        
-                        // Evaluate board for all players - reuse pre-allocated array
-                        for (player_idx, player_hole) in players.iter().enumerate() {
-                            player_ranks_eval[player_idx] = evaluate_7_card_hand(player_hole, &complete_board);
-                        }
-                        
-                        // Find winners using integer comparisons - reuse pre-allocated array
-                        let mut best_hand = player_ranks_eval[0];
-                        let mut winner_count = 1;
-                        winners[0] = 0;
-                        
-                        for i in 1..num_players {
-                            if player_ranks_eval[i] > best_hand {
-                                best_hand = player_ranks_eval[i];
-                                winner_count = 1;
-                                winners[0] = i;
-                            } else if player_ranks_eval[i] == best_hand {
-                                winners[winner_count] = i;
-                                winner_count += 1;
-                            }
-                        }
-                        
-                        // Update wins/ties using integer arithmetic (no f64 math in hot loop)
-                        if winner_count > 1 {
-                            // For ties, add fixed-point share (1/winner_count * TIE_MULTIPLIER) to each winner
-                            // Use lookup table for fast integer division
-                            let tie_share = tie_fractions_lut[winner_count.min(9) as usize];
-                            for i in 0..winner_count {
-                                ties[winners[i]] += tie_share;
-                            }
-                        } else {
-                            wins[winners[0]] += 1;
-                        }
-                        
-                        total_combos += 1;
-                    }
+    CardSet::try_from_cards(cards)?;
+    Ok(hand_value(evaluate_7_card_masks(&CardMasks::from_cards(cards))))
+}
+
+// Ranks of the five cards behind a hand value, in the order `encode_hand_rank` ranks
+// them: the made hand, then kickers; straights run down from the high card, so the
+// wheel's ace comes last
+fn best_five_ranks(value: &HandValue) -> [u8; 5] {
+    let straight = |high: u8| -> [u8; 5] {
+        let mut ranks = [0u8; 5];
+        for (i, rank) in ranks.iter_mut().enumerate() {
+            *rank = if high == 5 && i == 4 { 14 } else { high - i as u8 };
+        }
+        ranks
+    };
+    let t = &value.tiebreak;
+    match value.category {
+        9 => straight(14),
+        8 | 4 => straight(t[0]),
+        7 => [t[0], t[0], t[0], t[0], t[1]],
+        6 => [t[0], t[0], t[0], t[1], t[1]],
+        3 => [t[0], t[0], t[0], t[1], t[2]],
+        2 => [t[0], t[0], t[1], t[1], t[2]],
+        1 => [t[0], t[0], t[1], t[2], t[3]],
+        _ => [t[0], t[1], t[2], t[3], t[4]], // flush and high card: five ranks
+    }
+}
+
+/// `evaluate_hand` plus the five cards that make the hand
+///
+/// `best_five` in the result holds their indices into `cards`, in tiebreak order: the
+/// made hand, then kickers, and straights from the high card down (the wheel's ace
+/// last). Of equal-ranked cards outside a flush the earliest in `cards` is used.
+/// Exported to wasm as `best_hand`.
+pub fn best_hand(cards: &[Card]) -> Result<HandValue, EquityError> {
+    let mut value = evaluate_hand(cards)?;
+
+    // Flushes take every card from the suit with five or more (at most one in 7 cards)
+    let flush_suit = match value.category {
+        5 | 8 | 9 => (0..4).find(|&suit| cards.iter().filter(|card| card.suit == suit).count() >= 5),
+        _ => None,
+    };
+    let mut used = [false; 7];
+    let best_five = best_five_ranks(&value)
+        .iter()
+        .map(|&rank| {
+            let idx = (0..cards.len())
+                .find(|&idx| {
+                    let card = cards[idx];
+                    !used[idx] && card.rank == rank && flush_suit.is_none_or(|suit| card.suit == suit)
+                })
+                .expect("the evaluated hand is made of the input cards");
+            used[idx] = true;
+            idx
+        })
+        .collect();
+    value.best_five = Some(best_five);
+    Ok(value)
+}
+
+// Integer win/tie counters accumulated over every enumerated board
+//
+// Ties are counted per split size rather than as rounded shares: `ties` holds, for each
+// player, the boards they split n ways (n = 2..=num_players), so a 3-way tie is worth
+// exactly a third of a pot until the final division.
+struct EquityCounts {
+    wins: Vec<u64>,
+    ties: Vec<u64>, // ties[player * (num_players + 1) + n]
+    total: u64,
+}
+
+// Most players in one calculation: 23 hands leave 6 cards, enough to deal a board
+const MAX_PLAYERS: usize = 23;
+
+// Least common multiple of 1..=n: every n-way split is a whole number of 1/lcm pots
+fn lcm_up_to(n: usize) -> u64 {
+    fn gcd(a: u64, b: u64) -> u64 {
+        if b == 0 {
+            a
+        } else {
+            gcd(b, a % b)
+        }
+    }
+    (1..=n as u64).fold(1, |lcm, k| lcm / gcd(lcm, k) * k)
+}
+
+impl EquityCounts {
+    fn new(num_players: usize) -> Self {
+        EquityCounts {
+            wins: vec![0u64; num_players],
+            ties: vec![0u64; num_players * (num_players + 1)],
+            total: 0,
+        }
+    }
+
+    // Update wins/ties using integer arithmetic (no f64 math in hot loop)
+    #[inline(always)]
+    fn record(&mut self, winners: &[usize]) {
+        self.record_weighted(winners, 1);
+    }
+
+    // Add another partial enumeration's counters (exact: integer sums)
+    #[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+    fn merge(&mut self, other: &EquityCounts) {
+        for (win, other_win) in self.wins.iter_mut().zip(other.wins.iter()) {
+            *win += other_win;
+        }
+        for (tie, other_tie) in self.ties.iter_mut().zip(other.ties.iter()) {
+            *tie += other_tie;
+        }
+        self.total += other.total;
+    }
+
+    // Record a showdown that stands for `weight` equivalent boards
+    #[inline(always)]
+    fn record_weighted(&mut self, winners: &[usize], weight: u64) {
+        if winners.len() > 1 {
+            let stride = self.wins.len() + 1;
+            for &winner in winners {
+                self.ties[winner * stride + winners.len()] += weight;
+            }
+        } else {
+            self.wins[winners[0]] += weight;
+        }
+        self.total += weight;
+    }
+
+    // Boards `player` split with each number of winners (indices 0 and 1 are always 0)
+    fn player_ties(&self, player: usize) -> &[u64] {
+        let stride = self.wins.len() + 1;
+        &self.ties[player * stride..(player + 1) * stride]
+    }
+
+    // Pots `player` won through ties, sum of ties[n] / n: summed exactly in units of
+    // 1/lcm(1..=num_players) pots and divided once
+    fn tie_pots(&self, player: usize) -> f64 {
+        let lcm = lcm_up_to(self.wins.len());
+        let units: u128 = self
+            .player_ties(player)
+            .iter()
+            .enumerate()
+            .skip(2)
+            .map(|(ways, &count)| count as u128 * (lcm / ways as u64) as u128)
+            .sum();
+        units as f64 / lcm as f64
+    }
+
+    // Probability of splitting the pot per player, and of splitting it n ways
+    // (indexed like `player_ties`)
+    fn tie_breakdown(&self) -> (Vec<f64>, Vec<Vec<f64>>) {
+        let total = self.total.max(1) as f64;
+        (0..self.wins.len())
+            .map(|player| {
+                let ties = self.player_ties(player);
+                let chops: Vec<f64> = ties.iter().map(|&count| count as f64 / total).collect();
+                (ties.iter().sum::<u64>() as f64 / total, chops)
+            })
+            .unzip()
+    }
+
+    // The counters as exposed next to the fractions of a result
+    fn board_counts(&self) -> BoardCounts {
+        BoardCounts {
+            wins: self.wins.clone(),
+            ties: (0..self.wins.len()).map(|player| self.player_ties(player).to_vec()).collect(),
+        }
+    }
+}
+
+// Hand evaluator used by showdowns, selected at runtime with `set_evaluator_backend`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum EvaluatorBackend {
+    Direct, // evaluate_7_card_hand
+    Table,  // table_evaluator::TableBoard
+}
+
+// SIMD builds default to the table backend, the one their vector lanes speed up
+#[cfg(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128"))]
+const DEFAULT_BACKEND: EvaluatorBackend = EvaluatorBackend::Table;
+#[cfg(not(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128")))]
+const DEFAULT_BACKEND: EvaluatorBackend = EvaluatorBackend::Direct;
+
+static EVALUATOR_BACKEND: AtomicU8 = AtomicU8::new(DEFAULT_BACKEND as u8);
+
+impl EvaluatorBackend {
+    fn current() -> Self {
+        match EVALUATOR_BACKEND.load(Ordering::Relaxed) {
+            1 => EvaluatorBackend::Table,
+            _ => EvaluatorBackend::Direct,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            EvaluatorBackend::Direct => "direct",
+            EvaluatorBackend::Table => "table",
+        }
+    }
+}
+
+// Scratch buffers for scoring one complete board, reused across the hot loop
+struct Showdown {
+    player_ranks_eval: Vec<HandRank>,
+    winners: Vec<usize>,
+    // Fixed per calculation so strengths from different backends are never compared
+    backend: EvaluatorBackend,
+    // Table backend with fixed players (`for_players`): their hole cards in vector lanes
+    #[cfg(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128"))]
+    lanes: Option<simd::PlayerLanes>,
+}
+
+impl Showdown {
+    fn new(num_players: usize) -> Self {
+        Showdown {
+            player_ranks_eval: vec![0u64; num_players],
+            winners: vec![0usize; num_players],
+            backend: EvaluatorBackend::current(),
+            #[cfg(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128"))]
+            lanes: None,
+        }
+    }
+
+    // Showdowns of players that stay fixed over many boards; SIMD builds pack them
+    // into vector lanes once for the table backend
+    #[cfg(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128"))]
+    fn for_players(players: &[[Card; 2]]) -> Self {
+        let mut showdown = Showdown::new(players.len());
+        if showdown.backend == EvaluatorBackend::Table {
+            showdown.lanes = Some(simd::PlayerLanes::new(players));
+        }
+        showdown
+    }
+
+    #[cfg(not(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128")))]
+    fn for_players(players: &[[Card; 2]]) -> Self {
+        Showdown::new(players.len())
+    }
+
+    // Evaluate every player on a complete board and return the winning seats
+    #[inline(always)]
+    fn winners(&mut self, players: &[[Card; 2]], board: &[Card; 5]) -> &[usize] {
+        self.winners_on(players, &CardMasks::from_cards(board))
+    }
+
+    // Same as `winners`, for a complete board whose masks are already built
+    #[inline(always)]
+    fn winners_on(&mut self, players: &[[Card; 2]], board: &CardMasks) -> &[usize] {
+        // Fold each hole pair into the shared board masks - reuse pre-allocated array
+        match self.backend {
+            EvaluatorBackend::Direct => {
+                for (player_eval, player_hole) in self.player_ranks_eval.iter_mut().zip(players.iter()) {
+                    *player_eval = evaluate_7_card_masks(&board.with_hole(player_hole));
+                }
+            }
+            EvaluatorBackend::Table => {
+                let table_board = table_evaluator::TableBoard::new(board);
+                #[cfg(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128"))]
+                if let Some(lanes) = self.lanes.as_mut() {
+                    debug_assert!(lanes.holds(players));
+                    let winner_count = lanes.winners(&table_board, &mut self.winners);
+                    return &self.winners[..winner_count];
                 }
+                for (player_eval, player_hole) in self.player_ranks_eval.iter_mut().zip(players.iter()) {
+                    *player_eval = table_board.evaluate(player_hole) as HandRank;
+                }
+            }
+        }
+
+        // Find winners using integer comparisons - reuse pre-allocated array
+        let mut best_hand = self.player_ranks_eval[0];
+        let mut winner_count = 1;
+        self.winners[0] = 0;
+
+        for (i, &player_eval) in self.player_ranks_eval.iter().enumerate().skip(1) {
+            if player_eval > best_hand {
+                best_hand = player_eval;
+                winner_count = 1;
+                self.winners[0] = i;
+            } else if player_eval == best_hand {
+                self.winners[winner_count] = i;
+                winner_count += 1;
             }
         }
+
+        &self.winners[..winner_count]
     }
-    
-    // Convert integer counters to fractions (compute fractions once at the end)
-    let total_combos_f = total_combos as f64;
-    let tie_multiplier_f = TIE_MULTIPLIER as f64;
-    
-    // Convert wins (already integers) and ties (fixed-point) to fractions
-    let win_fractions: Vec<f64> = wins.iter().map(|&w| w as f64 / total_combos_f).collect();
-    let tie_fractions: Vec<f64> = ties.iter().map(|&t| t as f64 / tie_multiplier_f / total_combos_f).collect();
+}
+
+// Reject calculations without players or with more than `MAX_PLAYERS`
+fn check_player_count(num_players: usize) -> Result<(), EquityError> {
+    if num_players == 0 {
+        return Err(EquityError::NoPlayers);
+    }
+    if num_players > MAX_PLAYERS {
+        return Err(EquityError::TooManyPlayers {
+            players: num_players,
+            max: MAX_PLAYERS,
+        });
+    }
+    Ok(())
+}
+
+// Reject boards with more than 5 cards
+fn check_board_count(board_len: usize) -> Result<(), EquityError> {
+    if board_len > 5 {
+        return Err(EquityError::BadLength {
+            cards: "Board",
+            expected: "at most 5 cards",
+        });
+    }
+    Ok(())
+}
+
+/// Build the remaining deck (all 52 cards minus `known_cards`)
+///
+/// Rejects cards outside rank 2-14 / suit 0-3 and cards that appear twice,
+/// so impossible boards are never enumerated.
+fn build_remaining_deck(known_cards: &[Card]) -> Result<Vec<Card>, EquityError> {
+    Ok(CardSet::try_from_cards(known_cards)?.complement().to_vec())
+}
+
+// Parsed and validated card inputs shared by the equity exports
+struct EquityInputs {
+    players: Vec<[Card; 2]>,
+    known_board: Vec<Card>,
+    remaining_deck: Vec<Card>,
+}
+
+// Validate hole, board (0-5) and dead cards and build the remaining deck
+fn prepare_equity_inputs(
+    players: Vec<[Card; 2]>,
+    known_board: Vec<Card>,
+    dead: &[Card],
+) -> Result<EquityInputs, EquityError> {
+    check_player_count(players.len())?;
+    check_board_count(known_board.len())?;
+
+    // Collect all known cards (hole, board, dead) and build the remaining deck
+    let mut known_cards: Vec<Card> = players.iter().flatten().copied().collect();
+    known_cards.extend_from_slice(&known_board);
+    known_cards.extend_from_slice(dead);
+    let remaining_deck = build_remaining_deck(&known_cards)?;
+
+    let missing = 5 - known_board.len();
+    if remaining_deck.len() < missing {
+        return Err(EquityError::InsufficientDeck {
+            needed: missing,
+            available: remaining_deck.len(),
+        });
+    }
+
+    Ok(EquityInputs {
+        players,
+        known_board,
+        remaining_deck,
+    })
+}
+
+/// Exact enumeration of every board completion, shared by all streets
+///
+/// `known_board` holds 0-5 cards; the remaining `5 - known_board.len()` cards
+/// are drawn from `remaining_deck` in lexicographic index order. Natively (with the
+/// `rayon` feature) large enumerations are split by the deck index of the first
+/// dealt card across threads; integer counters make the merged result identical to
+/// the single-threaded one.
+fn enumerate_equity(players: &[[Card; 2]], known_board: &[Card], remaining_deck: &[Card]) -> EquityCounts {
+    let missing = 5 - known_board.len();
+    if remaining_deck.len() < missing {
+        return EquityCounts::new(players.len());
+    }
+    let first_indices = first_card_indices(remaining_deck.len(), missing);
+
+    #[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+    if board_count(remaining_deck.len(), missing) >= MIN_PARALLEL_BOARDS {
+        use rayon::prelude::*;
+        return first_indices
+            .into_par_iter()
+            .map(|first| enumerate_boards(players, known_board, remaining_deck, first..first + 1))
+            .reduce(
+                || EquityCounts::new(players.len()),
+                |mut acc, counts| {
+                    acc.merge(&counts);
+                    acc
+                },
+            );
+    }
+
+    enumerate_boards(players, known_board, remaining_deck, first_indices)
+}
+
+// Possible deck indices of the first dealt card (a single placeholder when the board
+// is already complete); needs `deck_len >= missing`
+fn first_card_indices(deck_len: usize, missing: usize) -> std::ops::Range<usize> {
+    if missing == 0 {
+        0..1
+    } else {
+        0..deck_len - missing + 1
+    }
+}
+
+// Smallest enumeration worth splitting across threads
+#[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+const MIN_PARALLEL_BOARDS: u64 = 20_000;
+
+/// Number of boards an exact calculation enumerates: ways to deal `missing` cards from
+/// `deck_len` undealt ones, C(deck_len, missing)
+pub fn board_count(deck_len: usize, missing: usize) -> u64 {
+    (0..missing as u64).fold(1, |acc, i| acc * (deck_len as u64 - i) / (i + 1))
+}
+
+// Enumerate the board completions whose first dealt card has a deck index in
+// `first_indices` (ignored when the board is already complete)
+fn enumerate_boards(
+    players: &[[Card; 2]],
+    known_board: &[Card],
+    remaining_deck: &[Card],
+    first_indices: std::ops::Range<usize>,
+) -> EquityCounts {
+    let mut enumerator = BoardEnumerator::new(players, known_board, remaining_deck, first_indices);
+    enumerator.step(u64::MAX);
+    enumerator.counts
+}
+
+// Resumable exact enumeration of the board completions whose first dealt card has a
+// deck index in a range: `step` visits a bounded number of boards and picks up where
+// the previous call stopped
+struct BoardEnumerator {
+    players: Vec<[Card; 2]>,
+    remaining_deck: Vec<Card>,
+    missing: usize,
+    // Board masks after the known cards and each dealt slot: board_masks[slot + 1]
+    // extends board_masks[slot], so only the slots that moved are recomputed
+    board_masks: [CardMasks; 6],
+    showdown: Showdown,
+    // Boards equivalent under the suit permutations the deal leaves free are
+    // evaluated once, on the canonical board, and weighted by the group size
+    symmetry: SuitSymmetry,
+    deck_sets: Vec<CardSet>,
+    // Deck indices of the current combination, advanced like an odometer
+    indices: [usize; 5],
+    // Highest index each slot may take (the first slot stays inside `first_indices`)
+    last_index: [usize; 5],
+    // First combination slot whose card changed since the board masks were last built
+    changed_from: usize,
+    counts: EquityCounts,
+    visited: u64, // boards visited so far, canonical or not
+    done: bool,
+}
+
+impl BoardEnumerator {
+    fn new(
+        players: &[[Card; 2]],
+        known_board: &[Card],
+        remaining_deck: &[Card],
+        first_indices: std::ops::Range<usize>,
+    ) -> Self {
+        let missing = 5 - known_board.len();
+        let deck_len = remaining_deck.len();
+
+        let mut indices = [0usize; 5];
+        for (slot, index) in indices.iter_mut().enumerate() {
+            *index = first_indices.start + slot;
+        }
+        let mut last_index = [0usize; 5];
+        for (slot, last) in last_index.iter_mut().enumerate().take(missing) {
+            *last = (deck_len + slot).saturating_sub(missing);
+        }
+        last_index[0] = last_index[0].min(first_indices.end.saturating_sub(1));
+
+        BoardEnumerator {
+            players: players.to_vec(),
+            remaining_deck: remaining_deck.to_vec(),
+            missing,
+            board_masks: [CardMasks::from_cards(known_board); 6],
+            showdown: Showdown::for_players(players),
+            symmetry: SuitSymmetry::new(players, known_board, remaining_deck),
+            deck_sets: remaining_deck.iter().map(|&card| CardSet::EMPTY.with(card)).collect(),
+            indices,
+            last_index,
+            changed_from: 0,
+            counts: EquityCounts::new(players.len()),
+            visited: 0,
+            done: deck_len < missing || first_indices.is_empty(),
+        }
+    }
+
+    // Visit up to `max_boards` more boards; returns true once every board has been visited
+    fn step(&mut self, max_boards: u64) -> bool {
+        let missing = self.missing;
+        let mut budget = max_boards;
+
+        while !self.done && budget > 0 {
+            let weight = if self.symmetry.is_trivial() {
+                1
+            } else {
+                let board = self.indices[..missing]
+                    .iter()
+                    .fold(CardSet::EMPTY, |board, &idx| board | self.deck_sets[idx]);
+                self.symmetry.orbit_size(board)
+            };
+
+            if weight > 0 {
+                // Only rebuild the masks of the board slots whose deck index moved
+                for slot in self.changed_from..missing {
+                    self.board_masks[slot + 1] = self.board_masks[slot];
+                    self.board_masks[slot + 1].add(self.remaining_deck[self.indices[slot]]);
+                }
+                self.changed_from = missing;
+
+                let winners = self.showdown.winners_on(&self.players, &self.board_masks[missing]);
+                self.counts.record_weighted(winners, weight);
+            }
+            self.visited += 1;
+            budget -= 1;
+
+            // Advance to the next combination (rightmost index that can still move)
+            let mut slot = missing;
+            loop {
+                if slot == 0 {
+                    self.done = true;
+                    break;
+                }
+                slot -= 1;
+                if self.indices[slot] < self.last_index[slot] {
+                    break;
+                }
+            }
+            if self.done {
+                break;
+            }
+            self.indices[slot] += 1;
+            for next in (slot + 1)..missing {
+                self.indices[next] = self.indices[next - 1] + 1;
+            }
+            self.changed_from = self.changed_from.min(slot);
+        }
+
+        self.done
+    }
+}
+
+/// Deterministic SplitMix64 generator so sampled results are reproducible from a seed
+struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    #[inline(always)]
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    // Uniform float in [0, 1) from the top 53 bits
+    #[inline(always)]
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    // Uniform integer in 0..bound without modulo bias (Lemire's multiply-shift with rejection)
+    #[inline(always)]
+    fn below(&mut self, bound: u32) -> u32 {
+        let mut product = (self.next_u64() as u32 as u64) * bound as u64;
+        if (product as u32) < bound {
+            let threshold = bound.wrapping_neg() % bound;
+            while (product as u32) < threshold {
+                product = (self.next_u64() as u32 as u64) * bound as u64;
+            }
+        }
+        (product >> 32) as u32
+    }
+}
+
+// Monte Carlo accumulator: integer counters plus per-player sum of squared pot shares
+struct SampledEquity {
+    counts: EquityCounts,
+    share_squares: Vec<f64>,
+}
+
+impl SampledEquity {
+    fn new(num_players: usize) -> Self {
+        SampledEquity {
+            counts: EquityCounts::new(num_players),
+            share_squares: vec![0.0; num_players],
+        }
+    }
+}
+
+/// Sample `samples` random board completions and accumulate them into `acc`
+///
+/// `deck` is scratch space holding the remaining deck; each sample draws the
+/// missing cards with a partial Fisher-Yates shuffle, so its order changes.
+fn sample_equity(
+    players: &[[Card; 2]],
+    known_board: &[Card],
+    deck: &mut [Card],
+    samples: u64,
+    rng: &mut Rng,
+    acc: &mut SampledEquity,
+) {
+    let known = known_board.len();
+    let missing = 5 - known;
+    let deck_len = deck.len() as u32;
+
+    let mut complete_board = [Card { rank: 0, suit: 0 }; 5];
+    complete_board[..known].copy_from_slice(known_board);
+    let mut showdown = Showdown::for_players(players);
+
+    for _ in 0..samples {
+        // Draw the missing cards into the front of the deck
+        for slot in 0..missing {
+            let pick = slot + rng.below(deck_len - slot as u32) as usize;
+            deck.swap(slot, pick);
+            complete_board[known + slot] = deck[slot];
+        }
+
+        let winners = showdown.winners(players, &complete_board);
+        let share = 1.0 / winners.len() as f64;
+        for &winner in winners {
+            acc.share_squares[winner] += share * share;
+        }
+        acc.counts.record(winners);
+    }
+}
+
+// Boards sampled between checks of the adaptive stopping rules
+const ADAPTIVE_BATCH_SAMPLES: u64 = 1_000;
+
+// Minimum boards before the precision target may stop sampling (avoids a zero
+// standard error after a handful of identical outcomes)
+const MIN_ADAPTIVE_SAMPLES: u64 = 1_000;
+
+/// Stopping limits for adaptive sampling (0 disables a limit)
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct AdaptiveLimits {
+    pub target_precision: f64, // max 95% confidence half-width of any player's equity
+    pub time_budget_ms: f64,
+    pub max_samples: u64,
+}
+
+// Whether this build can measure time: Date.now() in the wasm module, Instant natively
+// with `std` (std::time::Instant is unavailable in wasm). Other builds reject time budgets.
+const HAS_CLOCK: bool = cfg!(any(
+    all(target_arch = "wasm32", feature = "wasm"),
+    all(not(target_arch = "wasm32"), feature = "std")
+));
+
+// Elapsed wall-clock time (always 0 without a clock)
+struct Stopwatch {
+    #[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+    start_ms: f64,
+    #[cfg(all(not(target_arch = "wasm32"), feature = "std"))]
+    start: std::time::Instant,
+}
+
+impl Stopwatch {
+    fn start() -> Self {
+        Stopwatch {
+            #[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+            start_ms: js_sys::Date::now(),
+            #[cfg(all(not(target_arch = "wasm32"), feature = "std"))]
+            start: std::time::Instant::now(),
+        }
+    }
+
+    fn elapsed_ms(&self) -> f64 {
+        #[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+        {
+            js_sys::Date::now() - self.start_ms
+        }
+        #[cfg(all(not(target_arch = "wasm32"), feature = "std"))]
+        {
+            self.start.elapsed().as_secs_f64() * 1000.0
+        }
+        #[cfg(not(any(
+            all(target_arch = "wasm32", feature = "wasm"),
+            all(not(target_arch = "wasm32"), feature = "std")
+        )))]
+        {
+            0.0
+        }
+    }
+}
+
+// Widest 95% confidence half-width across all players
+fn max_ci95_half_width(sampled: &SampledEquity) -> f64 {
+    let (win_fractions, tie_fractions, _) = equity_fractions(&sampled.counts);
+    equity_std_errors(sampled.counts.total, &sampled.share_squares, &win_fractions, &tie_fractions)
+        .iter()
+        .fold(0.0, |widest, &se| f64::max(widest, 1.96 * se))
+}
+
+/// Sample in batches until the precision target, time budget or sample cap is hit
+fn sample_until(
+    players: &[[Card; 2]],
+    known_board: &[Card],
+    deck: &mut [Card],
+    limits: &AdaptiveLimits,
+    rng: &mut Rng,
+    acc: &mut SampledEquity,
+) -> StopReason {
+    let stopwatch = Stopwatch::start();
+
+    loop {
+        let mut batch = ADAPTIVE_BATCH_SAMPLES;
+        if limits.max_samples > 0 {
+            batch = batch.min(limits.max_samples - acc.counts.total);
+        }
+        sample_equity(players, known_board, deck, batch, rng, acc);
+
+        if limits.target_precision > 0.0
+            && acc.counts.total >= MIN_ADAPTIVE_SAMPLES
+            && max_ci95_half_width(acc) <= limits.target_precision
+        {
+            return StopReason::Precision;
+        }
+        if limits.max_samples > 0 && acc.counts.total >= limits.max_samples {
+            return StopReason::Samples;
+        }
+        if limits.time_budget_ms > 0.0 && stopwatch.elapsed_ms() >= limits.time_budget_ms {
+            return StopReason::Time;
+        }
+    }
+}
+
+// Convert integer counters to win/tie/lose fractions (computed once at the end)
+fn equity_fractions(counts: &EquityCounts) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+    // No boards yet (a job that has not been stepped): all zero instead of NaN
+    if counts.total == 0 {
+        let zeros = vec![0.0; counts.wins.len()];
+        return (zeros.clone(), zeros.clone(), zeros);
+    }
+
+    let total_combos_f = counts.total as f64;
+
+    // Convert wins and the exact tie shares to fractions
+    let win_fractions: Vec<f64> = counts.wins.iter().map(|&w| w as f64 / total_combos_f).collect();
+    let tie_fractions: Vec<f64> = (0..counts.wins.len())
+        .map(|player| counts.tie_pots(player) / total_combos_f)
+        .collect();
     let lose_fractions: Vec<f64> = win_fractions
         .iter()
         .zip(tie_fractions.iter())
         .map(|(&w, &t)| 1.0 - w - t)
         .collect();
-    
-    // Build JSON result
-    format!(
-        r#"{{"win":{:?},"tie":{:?},"lose":{:?},"samples":{}}}"#,
-        win_fractions, tie_fractions, lose_fractions, total_combos
-    )
+
+    (win_fractions, tie_fractions, lose_fractions)
+}
+
+// Exact equity counters as a result, with the split breakdown and the raw counts
+fn equity_result(counts: &EquityCounts) -> EquityResult {
+    let (win_fractions, tie_fractions, lose_fractions) = equity_fractions(counts);
+    let mut result = EquityResult::new(win_fractions, tie_fractions, lose_fractions, counts.total);
+    let (tie_probability, chops) = counts.tie_breakdown();
+    result.tie_probability = Some(tie_probability);
+    result.chops = Some(chops);
+    result.counts = Some(counts.board_counts());
+    result
+}
+
+// Per-player standard error of the mean equity (win + tie share) of a sampled run, from
+// the per-player sums of squared pot shares over `samples` boards
+fn equity_std_errors(samples: u64, share_squares: &[f64], win_fractions: &[f64], tie_fractions: &[f64]) -> Vec<f64> {
+    let n = samples as f64;
+    win_fractions
+        .iter()
+        .zip(tie_fractions.iter())
+        .zip(share_squares.iter())
+        .map(|((&w, &t), &sq)| {
+            if n < 2.0 {
+                return 0.0;
+            }
+            let mean = w + t;
+            // Unbiased sample variance of the per-board pot share
+            let variance = ((sq / n - mean * mean) * n / (n - 1.0)).max(0.0);
+            (variance / n).sqrt()
+        })
+        .collect()
+}
+
+// Normal approximation of each player's 95% interval: equity +/- 1.96 standard errors,
+// clamped to [0, 1]
+fn ci95_intervals(win_fractions: &[f64], tie_fractions: &[f64], std_errors: &[f64]) -> Vec<[f64; 2]> {
+    win_fractions
+        .iter()
+        .zip(tie_fractions.iter())
+        .zip(std_errors.iter())
+        .map(|((&w, &t), &se)| {
+            let equity = w + t;
+            [(equity - 1.96 * se).max(0.0), (equity + 1.96 * se).min(1.0)]
+        })
+        .collect()
+}
+
+// Sampled counters as a result with standard errors and 95% confidence intervals
+// (and, for adaptive runs, the limit that stopped sampling)
+fn sampled_result(sampled: &SampledEquity, stop_reason: Option<StopReason>) -> EquityResult {
+    let (win_fractions, tie_fractions, lose_fractions) = equity_fractions(&sampled.counts);
+    let std_errors = equity_std_errors(
+        sampled.counts.total,
+        &sampled.share_squares,
+        &win_fractions,
+        &tie_fractions,
+    );
+    let ci95 = ci95_intervals(&win_fractions, &tie_fractions, &std_errors);
+
+    let mut result = EquityResult::new(win_fractions, tie_fractions, lose_fractions, sampled.counts.total);
+    let (tie_probability, chops) = sampled.counts.tie_breakdown();
+    result.tie_probability = Some(tie_probability);
+    result.chops = Some(chops);
+    result.counts = Some(sampled.counts.board_counts());
+    result.std_error = Some(std_errors);
+    result.ci95 = Some(ci95);
+    result.stop_reason = stop_reason;
+    result
+}
+
+/// Exact equity of each player on a partial board (0-5 cards)
+///
+/// Enumerates every completion of the board from the deck without the hole, board and
+/// dead cards, or looks up heads-up preflop without dead cards when a preflop table is
+/// loaded. Exported to wasm as `calculate_equity`.
+pub fn exact_equity(players: &[[Card; 2]], board: &[Card], dead: &[Card]) -> Result<EquityResult, EquityError> {
+    let inputs = prepare_equity_inputs(players.to_vec(), board.to_vec(), dead)?;
+
+    // Heads-up preflop without dead cards is answered from the table when one is loaded
+    if inputs.players.len() == 2 && inputs.known_board.is_empty() && dead.is_empty() {
+        if let Some(counts) = lookup_preflop_table(inputs.players[0], inputs.players[1]) {
+            return Ok(equity_result(&counts));
+        }
+    }
+
+    let counts = enumerate_equity(&inputs.players, &inputs.known_board, &inputs.remaining_deck);
+    Ok(equity_result(&counts))
+}
+
+/// Monte Carlo equity over `samples` random board completions
+///
+/// The same seed always gives the same result. Exported to wasm as
+/// `calculate_equity_monte_carlo`.
+pub fn monte_carlo_equity(
+    players: &[[Card; 2]],
+    board: &[Card],
+    dead: &[Card],
+    samples: u64,
+    seed: u64,
+) -> Result<EquityResult, EquityError> {
+    if samples == 0 {
+        return Err(EquityError::InvalidArgument("Sample count must be positive".to_string()));
+    }
+
+    let mut inputs = prepare_equity_inputs(players.to_vec(), board.to_vec(), dead)?;
+
+    let mut rng = Rng::new(seed);
+    let mut sampled = SampledEquity::new(players.len());
+    sample_equity(
+        &inputs.players,
+        &inputs.known_board,
+        &mut inputs.remaining_deck,
+        samples,
+        &mut rng,
+        &mut sampled,
+    );
+    Ok(sampled_result(&sampled, None))
+}
+
+/// Equity sampled until the first of `limits` is reached
+///
+/// `stop_reason` in the result says which limit stopped sampling. Exported to wasm as
+/// `calculate_equity_adaptive`.
+pub fn adaptive_equity(
+    players: &[[Card; 2]],
+    board: &[Card],
+    dead: &[Card],
+    limits: &AdaptiveLimits,
+    seed: u64,
+) -> Result<EquityResult, EquityError> {
+    let AdaptiveLimits {
+        target_precision,
+        time_budget_ms,
+        max_samples,
+    } = *limits;
+    if target_precision.is_nan() || target_precision < 0.0 || time_budget_ms.is_nan() || time_budget_ms < 0.0 {
+        return Err(EquityError::InvalidArgument(
+            "Target precision and time budget must be non-negative".to_string(),
+        ));
+    }
+    if time_budget_ms > 0.0 && !HAS_CLOCK {
+        return Err(EquityError::InvalidArgument(
+            "Time budgets need the `std` feature (or the wasm module)".to_string(),
+        ));
+    }
+    if target_precision == 0.0 && time_budget_ms == 0.0 && max_samples == 0 {
+        return Err(EquityError::InvalidArgument(
+            "Adaptive sampling needs a target precision, time budget or sample limit".to_string(),
+        ));
+    }
+
+    let mut inputs = prepare_equity_inputs(players.to_vec(), board.to_vec(), dead)?;
+
+    let mut rng = Rng::new(seed);
+    let mut sampled = SampledEquity::new(players.len());
+    let stop_reason = sample_until(
+        &inputs.players,
+        &inputs.known_board,
+        &mut inputs.remaining_deck,
+        limits,
+        &mut rng,
+        &mut sampled,
+    );
+    Ok(sampled_result(&sampled, Some(stop_reason)))
 }
 
 /// Analyze what kind of improvement an out provides
@@ -471,83 +1346,16 @@ fn categorize_out_detailed(
     category_after
 }
 
-/// Compute turn outs for heads-up Texas Hold'em
-/// 
-/// Input format:
-/// - hero_ranks: 2 ranks for hero's hole cards
-/// - hero_suits: 2 suits for hero's hole cards
-/// - villain_ranks: 2 ranks for villain's hole cards
-/// - villain_suits: 2 suits for villain's hole cards
-/// - board_ranks: 4 ranks for turn board
-/// - board_suits: 4 suits for turn board
-/// 
-/// Returns JSON with outs result or suppression:
-/// {
-///   "suppressed": null | { "reason": "string", "baseline_win": 0.45, "baseline_tie": 0.0 },
-///   "win_outs": [{"rank": 14, "suit": 0, "category": 5}],
-///   "tie_outs": [{"rank": 13, "suit": 1, "category": 2}],
-///   "baseline_win": 0.15,
-///   "baseline_tie": 0.05,
-///   "baseline_lose": 0.80,
-///   "total_river_cards": 44
-/// }
-#[wasm_bindgen]
-pub fn compute_turn_outs(
-    hero_ranks: &[u8],
-    hero_suits: &[u8],
-    villain_ranks: &[u8],
-    villain_suits: &[u8],
-    board_ranks: &[u8],
-    board_suits: &[u8],
-) -> String {
-    // Validate inputs
-    if hero_ranks.len() != 2 || hero_suits.len() != 2 {
-        return r#"{"error":"Hero must have exactly 2 cards"}"#.to_string();
-    }
-    if villain_ranks.len() != 2 || villain_suits.len() != 2 {
-        return r#"{"error":"Villain must have exactly 2 cards"}"#.to_string();
-    }
-    if board_ranks.len() != 4 || board_suits.len() != 4 {
-        return r#"{"error":"Board must have exactly 4 cards (turn)"}"#.to_string();
-    }
-    
-    // Parse hero and villain hole cards
-    let hero_hole = [
-        Card { rank: hero_ranks[0], suit: hero_suits[0] },
-        Card { rank: hero_ranks[1], suit: hero_suits[1] },
-    ];
-    
-    let villain_hole = [
-        Card { rank: villain_ranks[0], suit: villain_suits[0] },
-        Card { rank: villain_ranks[1], suit: villain_suits[1] },
-    ];
-    
-    // Parse board (4 cards)
-    let board_4 = [
-        Card { rank: board_ranks[0], suit: board_suits[0] },
-        Card { rank: board_ranks[1], suit: board_suits[1] },
-        Card { rank: board_ranks[2], suit: board_suits[2] },
-        Card { rank: board_ranks[3], suit: board_suits[3] },
-    ];
-    
-    // Build remaining deck - all 52 cards minus known 8
-    let mut remaining_deck: Vec<Card> = Vec::with_capacity(44);
-    let mut is_known = [[false; 4]; 15]; // rank x suit matrix for fast lookup
-    
-    // Mark known cards
-    for card in &[hero_hole[0], hero_hole[1], villain_hole[0], villain_hole[1], 
-                  board_4[0], board_4[1], board_4[2], board_4[3]] {
-        is_known[card.rank as usize][card.suit as usize] = true;
-    }
-    
-    // Build remaining deck
-    for rank in 2..=14 {
-        for suit in 0..4 {
-            if !is_known[rank as usize][suit as usize] {
-                remaining_deck.push(Card { rank, suit });
-            }
-        }
-    }
+/// Hero's turn outs against one villain
+///
+/// Exported to wasm as `compute_turn_outs`.
+pub fn turn_outs(hero_hole: [Card; 2], villain_hole: [Card; 2], board_4: [Card; 4]) -> Result<OutsResult, EquityError> {
+    // River cards - all 52 cards minus the known 8 (rejects invalid or duplicate cards)
+    let river_cards = CardSet::try_from_cards(&[
+        hero_hole[0], hero_hole[1], villain_hole[0], villain_hole[1],
+        board_4[0], board_4[1], board_4[2], board_4[3],
+    ])?
+    .complement();
     
     // Evaluate all possible river cards and compute baseline equity
     let mut wins = 0u32;
@@ -566,8 +1374,8 @@ pub fn compute_turn_outs(
     complete_board[3] = board_4[3];
     
     // Evaluate all possible river cards
-    for river_card in &remaining_deck {
-        complete_board[4] = *river_card;
+    for river_card in river_cards {
+        complete_board[4] = river_card;
         
         let hero_rank = evaluate_7_card_hand(&hero_hole, &complete_board);
         let villain_rank = evaluate_7_card_hand(&villain_hole, &complete_board);
@@ -575,18 +1383,18 @@ pub fn compute_turn_outs(
         if hero_rank > villain_rank {
             wins += 1;
             // Use detailed categorization to understand what type of out this is
-            let category = categorize_out_detailed(&hero_hole, &board_4, *river_card, hero_rank);
-            win_outs.push((*river_card, category));
+            let category = categorize_out_detailed(&hero_hole, &board_4, river_card, hero_rank);
+            win_outs.push((river_card, category));
         } else if hero_rank == villain_rank {
             ties += 1;
-            let category = categorize_out_detailed(&hero_hole, &board_4, *river_card, hero_rank);
-            tie_outs.push((*river_card, category));
+            let category = categorize_out_detailed(&hero_hole, &board_4, river_card, hero_rank);
+            tie_outs.push((river_card, category));
         } else {
             loses += 1;
         }
     }
     
-    let total = remaining_deck.len() as f64;
+    let total = river_cards.len() as f64;
     let p_win = wins as f64 / total;
     let p_tie = ties as f64 / total;
     let p_lose = loses as f64 / total;
@@ -599,40 +1407,632 @@ pub fn compute_turn_outs(
             format!("Already winning/ahead ({:.1}% win): outs are less meaningful", p_win * 100.0)
         };
         
-        return format!(
-            r#"{{"suppressed":{{"reason":"{}","baseline_win":{:.4},"baseline_tie":{:.4}}},"win_outs":[],"tie_outs":[],"baseline_win":{:.4},"baseline_tie":{:.4},"baseline_lose":{:.4},"total_river_cards":{}}}"#,
-            reason, p_win, p_tie, p_win, p_tie, p_lose, remaining_deck.len()
-        );
+        return Ok(OutsResult {
+            suppressed: Some(OutsSuppressionReason {
+                reason,
+                baseline_win: p_win,
+                baseline_tie: p_tie,
+            }),
+            win_outs: Vec::new(),
+            tie_outs: Vec::new(),
+            baseline_win: p_win,
+            baseline_tie: p_tie,
+            baseline_lose: p_lose,
+            total_river_cards: river_cards.len(),
+        });
     }
     
-    // Build JSON arrays for win_outs and tie_outs
-    let win_outs_json: Vec<String> = win_outs
+    let out_card = |&(card, category): &(Card, u8)| OutCard {
+        rank: card.rank,
+        suit: card.suit,
+        category,
+    };
+    Ok(OutsResult {
+        suppressed: None,
+        win_outs: win_outs.iter().map(out_card).collect(),
+        tie_outs: tie_outs.iter().map(out_card).collect(),
+        baseline_win: p_win,
+        baseline_tie: p_tie,
+        baseline_lose: p_lose,
+        total_river_cards: river_cards.len(),
+    })
+}
+
+// A range calculation as a result; sampled runs add standard errors and 95% intervals
+fn range_equity_result(result: range_equity::RangeEquity) -> EquityResult {
+    let ci95 = result
+        .std_error
+        .as_ref()
+        .map(|std_errors| ci95_intervals(&result.win, &result.tie, std_errors));
+    let mut equity = EquityResult::new(result.win, result.tie, result.lose, result.samples);
+    equity.tie_probability = Some(result.tie_probability);
+    equity.chops = Some(result.chops);
+    equity.exact = Some(result.exact);
+    equity.std_error = result.std_error;
+    equity.ci95 = ci95;
+    equity
+}
+
+/// Equity with some seats holding random hands (`None`)
+///
+/// Exact when feasible, otherwise `samples` seeded deals with standard errors and 95%
+/// intervals; `exact` in the result says which. Exported to wasm as `calculate_equity_vs_random`.
+pub fn equity_vs_random(
+    seats: &[Option<[Card; 2]>],
+    board: &[Card],
+    dead: &[Card],
+    samples: u64,
+    seed: u64,
+) -> Result<EquityResult, EquityError> {
+    check_player_count(seats.len())?;
+    check_board_count(board.len())?;
+
+    let mut blocked_cards: Vec<Card> = seats.iter().flatten().flatten().copied().collect();
+    blocked_cards.extend_from_slice(board);
+    blocked_cards.extend_from_slice(dead);
+    let blocked = CardSet::try_from_cards(&blocked_cards)?;
+
+    // Known hands are single-combo ranges; random seats hold any two remaining cards
+    let ranges: Vec<Range> = seats
         .iter()
-        .map(|(card, category)| {
-            format!(
-                r#"{{"rank":{},"suit":{},"category":{}}}"#,
-                card.rank, card.suit, category
-            )
+        .map(|hole| match hole {
+            Some(cards) => Range::from_hand(*cards),
+            None => {
+                let mut range = Range::any_two();
+                range.remove_blocked(blocked);
+                range
+            }
         })
         .collect();
-    
-    let tie_outs_json: Vec<String> = tie_outs
+
+    // Deck without board and dead cards; range_equity removes the hole cards per deal
+    let board_and_dead = CardSet::from_cards(board) | CardSet::from_cards(dead);
+    let remaining_deck = board_and_dead.complement().to_vec();
+
+    let mut rng = Rng::new(seed);
+    let result = range_equity(&ranges, board, &remaining_deck, samples, &mut rng)?;
+    Ok(range_equity_result(result))
+}
+
+/// Parse a hand range and remove the combos that use `blocked` (board and dead) cards
+///
+/// `range` in the result is the range printed back in normalized notation.
+pub fn normalize_range(range: &str, blocked: &[Card]) -> Result<NormalizedRange, EquityError> {
+    let blocked = CardSet::try_from_cards(blocked)?;
+    let mut parsed = Range::parse(range)?;
+    parsed.remove_blocked(blocked);
+
+    Ok(NormalizedRange {
+        range: parsed.to_string(),
+        combos: parsed.combos().len(),
+        weighted_combos: parsed.total_weight(),
+    })
+}
+
+/// Equity of a hand or range against one or more opponent ranges
+///
+/// Combos that share a card with the board, dead cards or each other are skipped, every
+/// combo counts with its range weight, and the result is exact when small enough,
+/// otherwise `samples` seeded draws. Exported to wasm as `calculate_range_equity`.
+pub fn range_vs_range_equity(
+    hero_range: &str,
+    opponent_ranges: &[&str],
+    board: &[Card],
+    dead: &[Card],
+    samples: u64,
+    seed: u64,
+) -> Result<RangeEquityResult, EquityError> {
+    check_board_count(board.len())?;
+    if opponent_ranges.is_empty() {
+        return Err(EquityError::InvalidRange("At least one opponent range is required".to_string()));
+    }
+    check_player_count(1 + opponent_ranges.len())?;
+
+    let mut blocked_cards = board.to_vec();
+    blocked_cards.extend_from_slice(dead);
+    let blocked = CardSet::try_from_cards(&blocked_cards)?;
+    let remaining_deck = blocked.complement().to_vec();
+
+    let mut ranges: Vec<Range> = Vec::with_capacity(1 + opponent_ranges.len());
+    for text in std::iter::once(&hero_range).chain(opponent_ranges.iter()) {
+        let mut range = Range::parse(text)?;
+        range.remove_blocked(blocked);
+        ranges.push(range);
+    }
+
+    let mut rng = Rng::new(seed);
+    let result = range_equity(&ranges, board, &remaining_deck, samples, &mut rng)?;
+
+    let hero_combos = result
+        .hero_combos
         .iter()
-        .map(|(card, category)| {
-            format!(
-                r#"{{"rank":{},"suit":{},"category":{}}}"#,
-                card.rank, card.suit, category
-            )
+        .map(|combo_equity| HeroComboEquity {
+            combo: combo_equity.combo.notation(),
+            weight: combo_equity.combo.weight,
+            equity: combo_equity.equity,
         })
         .collect();
-    
-    format!(
-        r#"{{"suppressed":null,"win_outs":[{}],"tie_outs":[{}],"baseline_win":{:.4},"baseline_tie":{:.4},"baseline_lose":{:.4},"total_river_cards":{}}}"#,
-        win_outs_json.join(","),
-        tie_outs_json.join(","),
-        p_win,
-        p_tie,
-        p_lose,
-        remaining_deck.len()
-    )
+    Ok(RangeEquityResult {
+        equity: range_equity_result(result),
+        hero_combos,
+    })
+}
+
+// Heads-up preflop table shared by every calculation and thread (set by
+// `load_preflop_table`)
+static PREFLOP_TABLE: RwLock<Option<PreflopTable>> = RwLock::new(None);
+
+// Access to the table; it is only ever replaced whole, so a poisoned lock is still valid
+fn preflop_table() -> RwLockReadGuard<'static, Option<PreflopTable>> {
+    PREFLOP_TABLE.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn preflop_table_mut() -> RwLockWriteGuard<'static, Option<PreflopTable>> {
+    PREFLOP_TABLE.write().unwrap_or_else(PoisonError::into_inner)
+}
+
+// Table bytes compiled into the module with the `embedded-preflop-table` feature (set by
+// build.rs when preflop_table.bin exists)
+#[cfg(embedded_preflop_table)]
+const EMBEDDED_PREFLOP_TABLE: &[u8] = include_bytes!("../preflop_table.bin");
+
+// Exact heads-up preflop counts from the loaded (or embedded) table, if available
+fn lookup_preflop_table(hero: [Card; 2], villain: [Card; 2]) -> Option<EquityCounts> {
+    #[cfg(embedded_preflop_table)]
+    if preflop_table().is_none() {
+        let mut table = preflop_table_mut();
+        if table.is_none() {
+            *table = PreflopTable::from_bytes(EMBEDDED_PREFLOP_TABLE).ok();
+        }
+    }
+    preflop_table().as_ref()?.lookup(hero, villain)
+}
+
+/// Number of canonical heads-up preflop matchups in a complete table
+///
+/// Each of the 169x169 starting-hand matchups appears once per suit relationship
+/// (e.g. AKs vs QQ with a shared suit and without), up to swapping the players.
+pub fn preflop_matchup_count() -> usize {
+    preflop_table::canonical_matchups().len()
+}
+
+/// Exact table entries (12 bytes each) for canonical matchups `start..end`
+///
+/// Every matchup enumerates all 1,712,304 boards; the full table is an offline job.
+pub fn generate_preflop_table_entries(start: usize, end: usize) -> Vec<u8> {
+    let keys = preflop_table::canonical_matchups();
+    let end = end.min(keys.len());
+    let start = start.min(end);
+    let entries: Vec<_> = keys[start..end]
+        .iter()
+        .map(|&key| preflop_table::compute_entry(key))
+        .collect();
+    preflop_table::encode_entries(&entries)
+}
+
+/// Build a preflop table file (header + sorted entries) from generated entries
+pub fn assemble_preflop_table(entries: &[u8]) -> Result<Vec<u8>, EquityError> {
+    Ok(PreflopTable::from_raw_entries(entries)?.to_bytes())
+}
+
+/// Load a heads-up preflop table file for `lookup_preflop_equity` and `exact_equity`
+pub fn load_preflop_table(bytes: &[u8]) -> Result<PreflopTableInfo, EquityError> {
+    let table = PreflopTable::from_bytes(bytes)?;
+    let matchups = table.len();
+    *preflop_table_mut() = Some(table);
+    Ok(PreflopTableInfo { matchups })
+}
+
+/// Exact heads-up preflop equity from the loaded (or embedded) table
+///
+/// Fails if no table is loaded, the cards are invalid, or the matchup is missing.
+pub fn lookup_preflop_equity(players: [[Card; 2]; 2]) -> Result<EquityResult, EquityError> {
+    let inputs = prepare_equity_inputs(players.to_vec(), Vec::new(), &[])?;
+    let has_table = cfg!(embedded_preflop_table) || preflop_table().is_some();
+    if !has_table {
+        return Err(EquityError::PreflopTable("Preflop table not loaded".to_string()));
+    }
+    match lookup_preflop_table(inputs.players[0], inputs.players[1]) {
+        Some(counts) => Ok(equity_result(&counts)),
+        None => Err(EquityError::PreflopTable("Matchup not found in preflop table".to_string())),
+    }
+}
+
+/// Select the hand evaluator used by every equity calculation
+///
+/// "direct" (rank counting, the default) or "table" (lookup tables, built on first
+/// selection). Both order every 7-card hand identically, so results are the same.
+pub fn set_evaluator_backend(name: &str) -> Result<EvaluatorBackendInfo, EquityError> {
+    let backend = match name {
+        "direct" => EvaluatorBackend::Direct,
+        "table" => {
+            table_evaluator::init_tables();
+            EvaluatorBackend::Table
+        }
+        _ => return Err(EquityError::InvalidArgument(format!("Unknown evaluator backend: {}", name))),
+    };
+    EVALUATOR_BACKEND.store(backend as u8, Ordering::Relaxed);
+    Ok(EvaluatorBackendInfo {
+        backend: backend.as_str().to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn holes(texts: &[&str]) -> Vec<[Card; 2]> {
+        texts
+            .iter()
+            .map(|text| parse_card_list(text).unwrap().try_into().unwrap())
+            .collect()
+    }
+
+    fn remaining_deck(players: &[[Card; 2]], board: &[Card], dead: &[Card]) -> Vec<Card> {
+        let known: Vec<Card> = players.iter().flatten().chain(board).chain(dead).copied().collect();
+        build_remaining_deck(&known).unwrap()
+    }
+
+    fn assert_same_counts(actual: &EquityCounts, expected: &EquityCounts) {
+        assert_eq!(actual.wins, expected.wins);
+        assert_eq!(actual.ties, expected.ties);
+        assert_eq!(actual.total, expected.total);
+    }
+
+    #[test]
+    fn parallel_split_matches_serial_enumeration() {
+        let spots: [(&[&str], &str, &str); 3] = [
+            (&["AhKh", "QsQd", "7c6c"], "", "2c 3c 4c 5c 2d 3d 4d 5d 2h 3h 4h 5h 2s 3s 4s 5s"),
+            (&["AhAd", "KsKc", "QhJh", "Tc9c"], "2d", ""),
+            (&["9h8h", "AcKd"], "2h", ""),
+        ];
+        for (holes_text, board, dead) in spots {
+            let players = holes(holes_text);
+            let board = parse_card_list(board).unwrap();
+            let deck = remaining_deck(&players, &board, &parse_card_list(dead).unwrap());
+            let missing = 5 - board.len();
+            #[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+            assert!(board_count(deck.len(), missing) >= MIN_PARALLEL_BOARDS);
+
+            let serial = enumerate_boards(&players, &board, &deck, first_card_indices(deck.len(), missing));
+            assert_same_counts(&enumerate_equity(&players, &board, &deck), &serial);
+        }
+    }
+
+    #[test]
+    fn stepped_enumeration_matches_one_shot() {
+        // The enumeration an `EquityJob` drives, in steps of different sizes; the second
+        // spot is suit-reduced, so some visited boards carry no weight
+        let spots: [(&[&str], &str); 2] = [
+            (&["AhKh", "QsQd", "Jc Tc"], "9h 8c 2s"),
+            (&["AhAd", "KsQs"], "2c 7c 9c"),
+        ];
+        for (holes_text, board) in spots {
+            let players = holes(holes_text);
+            let board = parse_card_list(board).unwrap();
+            let deck = remaining_deck(&players, &board, &[]);
+            let first = first_card_indices(deck.len(), 2);
+            let boards = board_count(deck.len(), 2);
+            let one_shot = enumerate_boards(&players, &board, &deck, first.clone());
+
+            for max_boards in [1, 7, 100, boards - 1, boards] {
+                let mut enumerator = BoardEnumerator::new(&players, &board, &deck, first.clone());
+                let mut steps = 0;
+                while !enumerator.step(max_boards) {
+                    steps += 1;
+                    assert_eq!(enumerator.visited, steps * max_boards);
+                }
+                assert_eq!(enumerator.visited, boards);
+                assert!(enumerator.step(max_boards));
+                assert_same_counts(&enumerator.counts, &one_shot);
+            }
+        }
+    }
+
+    #[test]
+    fn monte_carlo_is_reproducible_from_its_seed() {
+        let players = holes(&["AhKh", "QsQd"]);
+        let board = parse_card_list("2c 7h 9h").unwrap();
+        let run = |seed| monte_carlo_equity(&players, &board, &[], 20_000, seed).unwrap();
+
+        let first = run(7);
+        assert_eq!(first.samples, 20_000);
+        assert_eq!(run(7), first);
+        assert_ne!(run(8).counts, first.counts);
+
+        // The exact equity is inside the 95% interval for this seed
+        let exact = exact_equity(&players, &board, &[]).unwrap();
+        for (player, [low, high]) in first.ci95.unwrap().into_iter().enumerate() {
+            let equity = exact.win[player] + exact.tie[player];
+            assert!(low <= equity && equity <= high);
+        }
+    }
+
+    #[test]
+    fn adaptive_sampling_stops_at_each_limit() {
+        let players = holes(&["AhKh", "QsQd"]);
+        let board = parse_card_list("2c 7h 9h").unwrap();
+        let run = |target_precision, time_budget_ms, max_samples| {
+            let limits = AdaptiveLimits {
+                target_precision,
+                time_budget_ms,
+                max_samples,
+            };
+            adaptive_equity(&players, &board, &[], &limits, 3).unwrap()
+        };
+
+        let capped = run(0.0, 0.0, 2_500);
+        assert_eq!(capped.stop_reason, Some(StopReason::Samples));
+        assert_eq!(capped.samples, 2_500);
+
+        let precise = run(0.02, 0.0, 1_000_000);
+        assert_eq!(precise.stop_reason, Some(StopReason::Precision));
+        assert!(precise.samples < 1_000_000);
+        for [low, high] in precise.ci95.unwrap() {
+            assert!(high - low <= 2.0 * 0.02 + 1e-12);
+        }
+
+        // A precision no run reaches, so only the clock can stop it
+        if HAS_CLOCK {
+            let timed = run(1e-9, 5.0, 0);
+            assert_eq!(timed.stop_reason, Some(StopReason::Time));
+            assert!(timed.samples >= ADAPTIVE_BATCH_SAMPLES);
+        }
+    }
+
+    #[test]
+    fn aces_against_a_random_hand_sample_their_known_equity() {
+        let aces = holes(&["AhAd"])[0];
+        let result = equity_vs_random(&[Some(aces), None], &[], &[], 100_000, 9).unwrap();
+        assert_eq!(result.exact, Some(false));
+        assert_eq!(result.samples, 100_000);
+        assert_eq!(result.std_error.as_ref().map(Vec::len), Some(2));
+
+        // Aces win 85.2% against a random hand
+        let [low, high] = result.ci95.unwrap()[0];
+        assert!(low <= 0.852 && 0.852 <= high);
+        assert!(high - low < 0.01);
+        assert_eq!(equity_vs_random(&[Some(aces), None], &[], &[], 100_000, 9).unwrap().win, result.win);
+
+        // Exact runs have no interval
+        let flop = parse_card_list("2c 7d 9h").unwrap();
+        let exact = equity_vs_random(&[Some(aces), None], &flop, &[], 1_000, 9).unwrap();
+        assert_eq!(exact.exact, Some(true));
+        assert_eq!((exact.std_error, exact.ci95), (None, None));
+    }
+
+    // The first `count` two-card hands in deck order: 2c2d, 2h2s, 3c3d, ...
+    fn deck_holes(count: usize) -> Vec<[Card; 2]> {
+        let deck = CardSet::FULL_DECK.to_vec();
+        deck.chunks_exact(2).take(count).map(|pair| [pair[0], pair[1]]).collect()
+    }
+
+    #[test]
+    fn invalid_inputs_give_typed_errors() {
+        let card = |rank, suit| Card { rank, suit };
+        let players = holes(&["AhAd", "KsKc"]);
+        let exact = |players: &[[Card; 2]], board: &str, dead: &[Card]| {
+            exact_equity(players, &parse_card_list(board).unwrap(), dead).unwrap_err()
+        };
+
+        assert_eq!(
+            exact(&players, "2c 3c 4c 5c 6c 7c", &[]),
+            EquityError::BadLength {
+                cards: "Board",
+                expected: "at most 5 cards"
+            }
+        );
+        assert_eq!(
+            evaluate_hand(&parse_card_list("Ah Kh Qh Jh").unwrap()).unwrap_err(),
+            EquityError::BadLength {
+                cards: "A hand",
+                expected: "5 to 7 cards"
+            }
+        );
+        assert_eq!(exact(&[[card(15, 0), card(2, 0)]], "", &[]), EquityError::InvalidRank(card(15, 0)));
+        assert_eq!(exact(&[[card(1, 0), card(2, 0)]], "", &[]), EquityError::InvalidRank(card(1, 0)));
+        assert_eq!(exact(&players, "", &[card(9, 4)]), EquityError::InvalidSuit(card(9, 4)));
+        assert_eq!(
+            evaluate_hand(&[card(2, 0), card(3, 0), card(4, 0), card(5, 0), card(6, 9)]).unwrap_err(),
+            EquityError::InvalidSuit(card(6, 9))
+        );
+
+        // Duplicates between players, player and board, board and dead, player and dead
+        let ace_of_hearts = card(14, 2);
+        assert_eq!(exact(&holes(&["AhAd", "AhKc"]), "", &[]), EquityError::DuplicateCard(ace_of_hearts));
+        assert_eq!(exact(&players, "2c 7d Ah", &[]), EquityError::DuplicateCard(ace_of_hearts));
+        assert_eq!(exact(&players, "2c 7d 9h", &[card(7, 1)]), EquityError::DuplicateCard(card(7, 1)));
+        assert_eq!(exact(&players, "", &[ace_of_hearts]), EquityError::DuplicateCard(ace_of_hearts));
+
+        assert_eq!(exact(&[], "", &[]), EquityError::NoPlayers);
+        assert_eq!(
+            exact(&deck_holes(24), "", &[]),
+            EquityError::TooManyPlayers { players: 24, max: 23 }
+        );
+
+        // 23 hands leave 6 cards: two dead cards leave too few for a board
+        let deck = CardSet::FULL_DECK.to_vec();
+        assert_eq!(
+            exact(&deck_holes(23), "", &deck[46..48]),
+            EquityError::InsufficientDeck { needed: 5, available: 4 }
+        );
+
+        // The sampling modes validate the same way
+        assert_eq!(
+            monte_carlo_equity(&deck_holes(24), &[], &[], 100, 1).unwrap_err(),
+            EquityError::TooManyPlayers { players: 24, max: 23 }
+        );
+        assert_eq!(
+            monte_carlo_equity(&holes(&["AhAd", "AhKc"]), &[], &[], 100, 1).unwrap_err(),
+            EquityError::DuplicateCard(ace_of_hearts)
+        );
+        assert_eq!(
+            equity_vs_random(&[Some(players[0]), None], &[], &[ace_of_hearts], 100, 1).unwrap_err(),
+            EquityError::DuplicateCard(ace_of_hearts)
+        );
+    }
+
+    #[test]
+    fn lcm_covers_every_split_size() {
+        assert_eq!(lcm_up_to(1), 1);
+        assert_eq!(lcm_up_to(2), 2);
+        assert_eq!(lcm_up_to(9), 2_520);
+        assert_eq!(lcm_up_to(MAX_PLAYERS), 5_354_228_880);
+        for n in 2..=MAX_PLAYERS as u64 {
+            assert_eq!(lcm_up_to(MAX_PLAYERS) % n, 0);
+        }
+    }
+
+    #[test]
+    fn split_pots_are_exact_fractions() {
+        // Everyone plays the royal flush on the board
+        let board = parse_card_list("Ts Js Qs Ks As").unwrap();
+        let seats = ["2c2d", "3c3d", "4c4d", "5c5d", "6c6d", "7c7d", "8c8d"];
+        for (ways, share) in [(3, 1.0 / 3.0), (6, 1.0 / 6.0), (7, 1.0 / 7.0)] {
+            let result = exact_equity(&holes(&seats[..ways]), &board, &[]).unwrap();
+            assert_eq!(result.tie, vec![share; ways]);
+            assert_eq!(result.win, vec![0.0; ways]);
+            assert_eq!(result.tie_probability, Some(vec![1.0; ways]));
+            for ties in result.counts.unwrap().ties {
+                assert_eq!(ties[ways], 1);
+            }
+        }
+
+        // 3-, 6- and 7-way splits add up in whole units of 1/lcm(1..=9) pots
+        let mut counts = EquityCounts::new(9);
+        for ways in [3, 6, 7] {
+            counts.record(&(0..ways).collect::<Vec<_>>());
+        }
+        assert_eq!(counts.tie_pots(0), 9.0 / 14.0); // 1/3 + 1/6 + 1/7
+        assert_eq!(counts.tie_pots(6), 1.0 / 7.0);
+        assert_eq!(counts.tie_pots(8), 0.0);
+    }
+
+    #[test]
+    fn equities_sum_to_one_with_many_players() {
+        // Every split size up to 23 ways, each several times, plus outright wins
+        let mut counts = EquityCounts::new(MAX_PLAYERS);
+        for round in 0..50 {
+            for ways in 1..=MAX_PLAYERS {
+                let winners: Vec<usize> = (0..ways).map(|seat| (seat + round) % MAX_PLAYERS).collect();
+                counts.record(&winners);
+            }
+        }
+
+        // Exactly one pot per board in units of 1/lcm pots, and within rounding as floats
+        let lcm = lcm_up_to(MAX_PLAYERS) as u128;
+        let units: u128 = (0..MAX_PLAYERS)
+            .map(|player| {
+                let ties = counts.player_ties(player);
+                counts.wins[player] as u128 * lcm
+                    + (2..ties.len()).map(|ways| ties[ways] as u128 * (lcm / ways as u128)).sum::<u128>()
+            })
+            .sum();
+        assert_eq!(units, counts.total as u128 * lcm);
+
+        let result = equity_result(&counts);
+        let total: f64 = result.win.iter().zip(&result.tie).map(|(w, t)| w + t).sum();
+        assert!((total - 1.0).abs() < 1e-12);
+
+        // 23 hands leave Kh Ks and the four aces: KcKd makes quad kings on the four boards
+        // with both kings, and the two boards with four aces are 23-way splits
+        let result = exact_equity(&deck_holes(MAX_PLAYERS), &[], &[]).unwrap();
+        assert_eq!(result.samples, 6);
+        assert_eq!(result.win[MAX_PLAYERS - 1], 4.0 / 6.0);
+        for ties in result.counts.unwrap().ties {
+            assert_eq!(ties[MAX_PLAYERS], 2);
+        }
+        let total: f64 = result.win.iter().chain(&result.tie).sum();
+        assert!((total - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn evaluates_and_describes_five_and_six_card_hands() {
+        let evaluate = |text: &str| {
+            let value = evaluate_hand(&parse_card_list(text).unwrap()).unwrap();
+            let description = describe_hand(&value, "en").unwrap();
+            (value.category, value.tiebreak, description)
+        };
+        let expect = |category, tiebreak: &[u8], description: &str| {
+            (category, tiebreak.to_vec(), description.to_string())
+        };
+
+        // Five cards
+        assert_eq!(evaluate("Ac 2d 3h 4s 5c"), expect(4, &[5], "Straight, Five high (wheel)"));
+        assert_eq!(evaluate("Qh Qd 9c 4s 2h"), expect(1, &[12, 9, 4, 2], "Pair of Queens, Nine kicker"));
+        assert_eq!(evaluate("Kc 9d 7h 4s 2c"), expect(0, &[13, 9, 7, 4, 2], "King-Nine high"));
+        assert_eq!(evaluate("Ts Js Qs Ks As"), expect(9, &[], "Royal flush"));
+        assert_eq!(evaluate("5d 4d 3d 2d Ad"), expect(8, &[5], "Straight flush, Five high (wheel)"));
+
+        // Six cards: the best five of them
+        assert_eq!(evaluate("Ah 9h 7h 4h 2h Kh"), expect(5, &[14, 13, 9, 7, 4], "Flush, Ace high"));
+        assert_eq!(evaluate("Ac 2d 3h 4s 5c 6d"), expect(4, &[6], "Straight, Six high"));
+        assert_eq!(evaluate("Jc Jd 4h 4s 9c 9d"), expect(2, &[11, 9, 4], "Two pair, Jacks and Nines, Four kicker"));
+        assert_eq!(evaluate("7c 7d 7h 2s 2c Ad"), expect(6, &[7, 2], "Full house, Sevens full of Twos"));
+        assert_eq!(evaluate("8c 8d 8h 8s Kc Ad"), expect(7, &[8, 14], "Four of a kind, Eights, Ace kicker"));
+
+        let bad_length = EquityError::BadLength {
+            cards: "A hand",
+            expected: "5 to 7 cards",
+        };
+        let deck = CardSet::FULL_DECK.to_vec();
+        assert_eq!(evaluate_hand(&deck[..4]), Err(bad_length.clone()));
+        assert_eq!(evaluate_hand(&deck[..8]), Err(bad_length.clone()));
+        assert_eq!(best_hand(&deck[..4]), Err(bad_length));
+    }
+
+    fn cards_at(cards: &[Card], indices: &[usize]) -> Vec<Card> {
+        indices.iter().map(|&idx| cards[idx]).collect()
+    }
+
+    #[test]
+    fn best_five_re_evaluates_to_the_same_hand() {
+        let deck = CardSet::FULL_DECK.to_vec();
+        let mut rng = Rng::new(11);
+        for hand_size in [5, 6, 7] {
+            for _ in 0..20_000 {
+                let mut hand = CardSet::EMPTY;
+                while hand.len() < hand_size {
+                    hand.insert(deck[rng.below(52) as usize]);
+                }
+                let mut cards = hand.to_vec();
+                // Shuffle so indices do not follow deck order
+                for i in (1..cards.len()).rev() {
+                    cards.swap(i, rng.below(i as u32 + 1) as usize);
+                }
+
+                let value = best_hand(&cards).unwrap();
+                let best_five = value.best_five.clone().unwrap();
+                assert_eq!(best_five.len(), 5);
+                assert_eq!(CardSet::from_cards(&cards_at(&cards, &best_five)).len(), 5);
+                let five = evaluate_hand(&cards_at(&cards, &best_five)).unwrap();
+                assert_eq!((five.category, five.tiebreak), (value.category, value.tiebreak));
+            }
+        }
+    }
+
+    #[test]
+    fn best_five_is_in_tiebreak_order() {
+        let best = |text: &str| {
+            let cards = parse_card_list(text).unwrap();
+            let value = best_hand(&cards).unwrap();
+            cards_at(&cards, &value.best_five.unwrap())
+                .iter()
+                .map(|card| card.to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        // Wheels run from the five down, ace last
+        assert_eq!(best("Ac 2d 3h 4s 5c"), "5c 4s 3h 2d Ac");
+        assert_eq!(best("Ac 2d 3h 4s 5c 5d 9s"), "5c 4s 3h 2d Ac");
+        assert_eq!(best("5h 4h 3h 2h Ah Kh"), "5h 4h 3h 2h Ah");
+        // Six cards: a higher straight beats the wheel
+        assert_eq!(best("Ac 2d 3h 4s 5c 6d"), "6d 5c 4s 3h 2d");
+        // Pair, then kickers high to low; the earliest of equal ranks is used
+        assert_eq!(best("Ah Kd 3c 7s As 2d 9h"), "Ah As Kd 9h 7s");
+        assert_eq!(best("Kc Kd Ks Qh Qd Qc 2c"), "Kc Kd Ks Qh Qd");
+        // Flushes come from the flush suit only
+        assert_eq!(best("9c 8c 6c 4c 2c Ac Ad"), "Ac 9c 8c 6c 4c");
+        assert_eq!(best("Ts 2h 3h 4h 9h 6h Th"), "Th 9h 6h 4h 3h");
+    }
 }
@@ -0,0 +1,371 @@
+// Command-line interface to the equity engine
+//
+// Runs the same Rust calculations as the wasm module, natively, for scripts and
+// shells. Cards are written in notation ("AhAd", "Ts 9s 2c") or with the server's
+// numeric ranks ("14h 14d", "10s 9s 2c"). Output is a human-readable table, or the
+// wasm module's result object as JSON with --json. `batch` answers NDJSON requests
+// from stdin (see batch.rs), and `preflop-table` writes the heads-up preflop table.
+
+mod batch;
+
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::process::ExitCode;
+use std::time::Instant;
+
+#[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+use rayon::prelude::*;
+use wasm_equity::{
+    assemble_preflop_table, best_hand, describe_hand, exact_equity, generate_preflop_table_entries,
+    monte_carlo_equity, parse_card_list, preflop_matchup_count, turn_outs, Card, EquityResult, HandValue, OutCard,
+    OutsResult,
+};
+
+const USAGE: &str = "\
+Usage:
+  wasm-equity equity <hole> <hole>... [--board CARDS] [--dead CARDS] [--samples N] [--seed N] [--json]
+  wasm-equity outs <hero> <villain> --board CARDS [--json]
+  wasm-equity eval <cards> [--json]
+  wasm-equity batch < requests.jsonl > results.jsonl
+  wasm-equity bench [--runs N]
+  wasm-equity preflop-table [--out PATH]
+
+Cards: \"AhAd\", \"Ah Kd 7c\" or numeric ranks \"14h 13d 7c\" (suits c, d, h, s).
+equity enumerates every board unless --samples is given (Monte Carlo, seeded).
+batch reads CalculateEquityRequest / CalculateOutsRequest JSON lines and writes one
+result (or {\"error\": ...}) line per request, in input order.
+preflop-table enumerates every canonical heads-up preflop matchup (in parallel) and
+writes the table to PATH (default preflop_table.bin).";
+
+// Command line split into positional arguments and --name value options
+struct Args {
+    positional: Vec<String>,
+    options: Vec<(String, String)>,
+    json: bool,
+}
+
+impl Args {
+    fn parse(args: &[String]) -> Result<Args, String> {
+        let mut parsed = Args {
+            positional: Vec::new(),
+            options: Vec::new(),
+            json: false,
+        };
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            match arg.strip_prefix("--") {
+                Some("json") => parsed.json = true,
+                Some(name) => {
+                    let value = iter.next().ok_or_else(|| format!("--{} needs a value", name))?;
+                    parsed.options.push((name.to_string(), value.clone()));
+                }
+                None => parsed.positional.push(arg.clone()),
+            }
+        }
+        Ok(parsed)
+    }
+
+    fn option(&self, name: &str) -> Option<&str> {
+        self.options
+            .iter()
+            .rev()
+            .find(|(option, _)| option == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    // Reject options the command does not know, so typos are not silently ignored
+    fn check_options(&self, known: &[&str]) -> Result<(), String> {
+        match self.options.iter().find(|(name, _)| !known.contains(&name.as_str())) {
+            Some((name, _)) => Err(format!("Unknown option --{}", name)),
+            None => Ok(()),
+        }
+    }
+
+    fn cards(&self, name: &str) -> Result<Vec<Card>, String> {
+        match self.option(name) {
+            Some(text) => parse_card_list(text).map_err(|error| error.to_string()),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    fn number(&self, name: &str) -> Result<Option<u64>, String> {
+        self.option(name)
+            .map(|text| {
+                text.parse()
+                    .map_err(|_| format!("--{} must be a non-negative integer: {}", name, text))
+            })
+            .transpose()
+    }
+}
+
+fn parse_hole(text: &str) -> Result<[Card; 2], String> {
+    match parse_card_list(text).map_err(|error| error.to_string())?[..] {
+        [first, second] => Ok([first, second]),
+        _ => Err(format!("A hand must have exactly 2 cards: {}", text)),
+    }
+}
+
+fn card_list(cards: &[Card]) -> String {
+    if cards.is_empty() {
+        return "-".to_string();
+    }
+    cards.iter().map(|card| card.to_string()).collect::<Vec<_>>().join(" ")
+}
+
+fn percent(fraction: f64) -> String {
+    format!("{:.2}%", fraction * 100.0)
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> String {
+    serde_json::to_string(value).expect("results serialize to JSON")
+}
+
+fn equity_command(args: &Args) -> Result<String, String> {
+    args.check_options(&["board", "dead", "samples", "seed"])?;
+    let players = args
+        .positional
+        .iter()
+        .map(|text| parse_hole(text))
+        .collect::<Result<Vec<_>, _>>()?;
+    let board = args.cards("board")?;
+    let dead = args.cards("dead")?;
+
+    let result = match args.number("samples")? {
+        Some(samples) => {
+            let seed = args.number("seed")?.unwrap_or(0);
+            monte_carlo_equity(&players, &board, &dead, samples, seed)
+        }
+        None => exact_equity(&players, &board, &dead),
+    }
+    .map_err(|error| error.to_string())?;
+
+    if args.json {
+        return Ok(to_json(&result));
+    }
+    Ok(format_equity(&players, &board, &dead, &result))
+}
+
+fn format_equity(players: &[[Card; 2]], board: &[Card], dead: &[Card], result: &EquityResult) -> String {
+    let mut lines = vec![
+        format!("Board: {}", card_list(board)),
+        format!("Dead:  {}", card_list(dead)),
+    ];
+    match &result.std_error {
+        Some(_) => lines.push(format!("Sampled {} boards", result.samples)),
+        None => lines.push(format!("Enumerated {} boards", result.samples)),
+    }
+    lines.push(String::new());
+    lines.push(format!(
+        "{:<8}{:<8}{:>9}{:>9}{:>9}{:>9}",
+        "Player", "Hand", "Win", "Tie", "Lose", "Equity"
+    ));
+    for (idx, hole) in players.iter().enumerate() {
+        let mut line = format!(
+            "{:<8}{:<8}{:>9}{:>9}{:>9}{:>9}",
+            idx + 1,
+            format!("{}{}", hole[0], hole[1]),
+            percent(result.win[idx]),
+            percent(result.tie[idx]),
+            percent(result.lose[idx]),
+            percent(result.win[idx] + result.tie[idx])
+        );
+        // Sampled results: 95% confidence half-width of the equity
+        if let Some(std_errors) = &result.std_error {
+            line.push_str(&format!("  ±{}", percent(1.96 * std_errors[idx])));
+        }
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+fn outs_command(args: &Args) -> Result<String, String> {
+    args.check_options(&["board"])?;
+    let [hero, villain] = match &args.positional[..] {
+        [hero, villain] => [parse_hole(hero)?, parse_hole(villain)?],
+        _ => return Err("outs needs the hero's and the villain's hand".to_string()),
+    };
+    let board: [Card; 4] = args
+        .cards("board")?
+        .try_into()
+        .map_err(|_| "outs needs a turn board of exactly 4 cards (--board)".to_string())?;
+
+    let result = turn_outs(hero, villain, board).map_err(|error| error.to_string())?;
+    if args.json {
+        return Ok(to_json(&result));
+    }
+    Ok(format_outs(&result))
+}
+
+fn format_outs(result: &OutsResult) -> String {
+    let out_cards = |outs: &[OutCard]| {
+        let cards: Vec<Card> = outs
+            .iter()
+            .filter_map(|out| Card::new(out.rank, out.suit).ok())
+            .collect();
+        format!("({}): {}", cards.len(), card_list(&cards))
+    };
+
+    let mut lines = vec![format!(
+        "Baseline: win {}, tie {}, lose {} over {} river cards",
+        percent(result.baseline_win),
+        percent(result.baseline_tie),
+        percent(result.baseline_lose),
+        result.total_river_cards
+    )];
+    match &result.suppressed {
+        Some(suppressed) => lines.push(format!("Outs suppressed: {}", suppressed.reason)),
+        None => {
+            lines.push(format!("Win outs {}", out_cards(&result.win_outs)));
+            lines.push(format!("Tie outs {}", out_cards(&result.tie_outs)));
+        }
+    }
+    lines.join("\n")
+}
+
+fn eval_command(args: &Args) -> Result<String, String> {
+    args.check_options(&[])?;
+    let cards = parse_card_list(&args.positional.join(" ")).map_err(|error| error.to_string())?;
+    let value = best_hand(&cards).map_err(|error| error.to_string())?;
+    if args.json {
+        return Ok(to_json(&value));
+    }
+    let description = describe_hand(&value, "en").map_err(|error| error.to_string())?;
+    Ok(format_hand_value(&cards, &value, &description))
+}
+
+fn format_hand_value(cards: &[Card], value: &HandValue, description: &str) -> String {
+    let mut line = format!("{}: {}", card_list(cards), description);
+    if let Some(best_five) = &value.best_five {
+        let best_cards: Vec<Card> = best_five.iter().map(|&idx| cards[idx]).collect();
+        line.push_str(&format!("\nBest five: {}", card_list(&best_cards)));
+    }
+    line
+}
+
+// Exact preflop all-ins timed by `bench` (the cases benchmark.js used to run)
+const BENCH_CASES: [(&str, &[&str]); 6] = [
+    ("AA vs KK (heads-up)", &["AhAd", "KhKd"]),
+    ("AA vs KK vs QQ (3-way)", &["AhAd", "KhKd", "QhQd"]),
+    ("AKs vs JJ (heads-up)", &["AhKh", "JhJd"]),
+    ("72o vs AA (heads-up)", &["7c2d", "AhAd"]),
+    ("AA vs KK vs QQ vs JJ (4-way)", &["AhAd", "KhKd", "QhQd", "JhJd"]),
+    (
+        "AA-66 (9-way)",
+        &["AhAd", "KhKd", "QhQd", "JhJd", "ThTd", "9h9d", "8h8d", "7h7d", "6h6d"],
+    ),
+];
+
+fn bench_command(args: &Args) -> Result<String, String> {
+    args.check_options(&["runs"])?;
+    let runs = args.number("runs")?.unwrap_or(1).max(1);
+
+    let mut lines = vec![format!(
+        "{:<32}{:>12}{:>12}{:>16}",
+        "Case", "Boards", "Best", "Boards/s"
+    )];
+    for (name, holes) in BENCH_CASES {
+        let players = holes.iter().map(|text| parse_hole(text)).collect::<Result<Vec<_>, _>>()?;
+        let mut best = f64::INFINITY;
+        let mut boards = 0;
+        for _ in 0..runs {
+            let start = Instant::now();
+            let result = exact_equity(&players, &[], &[]).map_err(|error| error.to_string())?;
+            best = best.min(start.elapsed().as_secs_f64());
+            boards = result.samples;
+        }
+        lines.push(format!(
+            "{:<32}{:>12}{:>10.1}ms{:>16.0}",
+            name,
+            boards,
+            best * 1000.0,
+            boards as f64 / best
+        ));
+    }
+    Ok(lines.join("\n"))
+}
+
+fn batch_command(args: &Args) -> Result<String, String> {
+    args.check_options(&[])?;
+    let output = io::BufWriter::new(io::stdout().lock());
+    batch::run(io::stdin().lock(), output).map_err(|error| error.to_string())?;
+    Ok(String::new())
+}
+
+// Matchups generated between two progress lines of `preflop-table`
+const PREFLOP_TABLE_CHUNK: usize = 128;
+
+// Table entries for matchups `start..end`, one matchup per task in native builds with
+// the `rayon` feature (each matchup enumerates 1,712,304 boards)
+fn preflop_table_chunk(start: usize, end: usize) -> Vec<u8> {
+    #[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+    {
+        (start..end)
+            .into_par_iter()
+            .flat_map_iter(|idx| generate_preflop_table_entries(idx, idx + 1))
+            .collect()
+    }
+    #[cfg(not(all(feature = "rayon", not(target_arch = "wasm32"))))]
+    {
+        generate_preflop_table_entries(start, end)
+    }
+}
+
+fn preflop_table_command(args: &Args) -> Result<String, String> {
+    args.check_options(&["out"])?;
+    let path = args.option("out").unwrap_or("preflop_table.bin");
+    let total = preflop_matchup_count();
+
+    let start_time = Instant::now();
+    let mut entries = Vec::new();
+    let mut stderr = io::stderr().lock();
+    for start in (0..total).step_by(PREFLOP_TABLE_CHUNK) {
+        let end = (start + PREFLOP_TABLE_CHUNK).min(total);
+        entries.extend(preflop_table_chunk(start, end));
+        let elapsed = start_time.elapsed().as_secs_f64();
+        let _ = writeln!(
+            stderr,
+            "{}/{} matchups, {:.0}s elapsed, ~{:.0}s remaining",
+            end,
+            total,
+            elapsed,
+            elapsed / end as f64 * (total - end) as f64
+        );
+    }
+
+    let table = assemble_preflop_table(&entries).map_err(|error| error.to_string())?;
+    fs::write(path, &table).map_err(|error| format!("Cannot write {}: {}", path, error))?;
+    Ok(format!("Wrote {} matchups ({} bytes) to {}", total, table.len(), path))
+}
+
+fn run(args: &[String]) -> Result<String, String> {
+    let (command, rest) = args.split_first().ok_or_else(|| USAGE.to_string())?;
+    let args = Args::parse(rest)?;
+    match command.as_str() {
+        "equity" => equity_command(&args),
+        "outs" => outs_command(&args),
+        "eval" => eval_command(&args),
+        "batch" => batch_command(&args),
+        "bench" => bench_command(&args),
+        "preflop-table" => preflop_table_command(&args),
+        "help" | "--help" | "-h" => Ok(USAGE.to_string()),
+        _ => Err(format!("Unknown command: {}\n\n{}", command, USAGE)),
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match run(&args) {
+        Ok(output) => {
+            // `batch` has already written its results
+            if !output.is_empty() {
+                println!("{}", output);
+            }
+            ExitCode::SUCCESS
+        }
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,308 @@
+// Precomputed heads-up preflop equity table
+//
+// Every heads-up preflop matchup is reduced to a canonical form under the 24 suit
+// permutations and the swap of the two players, which covers each of the 169x169
+// starting-hand matchups together with all of its suit-relationship variants
+// (e.g. AKs vs QQ sharing one suit, or none). Exact board counts for each
+// canonical matchup are stored in a compact little-endian binary table:
+//
+// - header (12 bytes): magic "SIPF", version u16, reserved u16, entry count u32
+// - entries (12 bytes each, sorted by key): key u32, wins_a u32, wins_b u32
+//
+// The key packs the four card indices (6 bits each) of player A's and player B's
+// hands; ties are the remaining boards out of C(48, 5) = 1,712,304.
+
+use std::collections::BTreeSet;
+
+use crate::card_set::CardSet;
+use crate::error::EquityError;
+use crate::symmetry::SUIT_PERMUTATIONS;
+use crate::{enumerate_equity, Card, EquityCounts};
+
+const MAGIC: &[u8; 4] = b"SIPF";
+const VERSION: u16 = 1;
+const HEADER_LEN: usize = 12;
+const ENTRY_LEN: usize = 12;
+
+/// Boards per heads-up preflop matchup: C(48, 5)
+pub const BOARDS_PER_MATCHUP: u32 = 1_712_304;
+
+// Pack a hand's two card indices, higher index first
+fn hand_bits(hand: [Card; 2], permutation: &[u8; 4]) -> u32 {
+    let a = CardSet::index(Card { suit: permutation[hand[0].suit as usize], ..hand[0] });
+    let b = CardSet::index(Card { suit: permutation[hand[1].suit as usize], ..hand[1] });
+    (a.max(b) << 6) | a.min(b)
+}
+
+/// Canonical key of a heads-up matchup, and whether players A/B are swapped
+/// relative to the (hero, villain) order passed in
+pub fn canonical_key(hero: [Card; 2], villain: [Card; 2]) -> (u32, bool) {
+    let mut best = (u32::MAX, false);
+    for permutation in &SUIT_PERMUTATIONS {
+        let h = hand_bits(hero, permutation);
+        let v = hand_bits(villain, permutation);
+        let candidates = [((h << 12) | v, false), ((v << 12) | h, true)];
+        for candidate in candidates {
+            if candidate.0 < best.0 {
+                best = candidate;
+            }
+        }
+    }
+    best
+}
+
+// Decode a key back into player A's and player B's hands
+fn key_hands(key: u32) -> ([Card; 2], [Card; 2]) {
+    let card = |shift: u32| CardSet::card_at((key >> shift) & 0x3f);
+    ([card(18), card(12)], [card(6), card(0)])
+}
+
+/// Exact board counts for one canonical matchup
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MatchupEntry {
+    pub key: u32,
+    pub wins_a: u32,
+    pub wins_b: u32,
+}
+
+/// Every canonical heads-up preflop matchup key, sorted
+pub fn canonical_matchups() -> Vec<u32> {
+    let deck = CardSet::FULL_DECK.to_vec();
+    let mut hands: Vec<[Card; 2]> = Vec::with_capacity(1326);
+    for (i, &a) in deck.iter().enumerate() {
+        for &b in &deck[i + 1..] {
+            hands.push([a, b]);
+        }
+    }
+
+    let mut keys = BTreeSet::new();
+    for (i, &hero) in hands.iter().enumerate() {
+        for &villain in &hands[i + 1..] {
+            if hero.iter().any(|card| villain.contains(card)) {
+                continue;
+            }
+            keys.insert(canonical_key(hero, villain).0);
+        }
+    }
+    keys.into_iter().collect()
+}
+
+/// Exactly enumerate all preflop boards for one canonical matchup
+pub fn compute_entry(key: u32) -> MatchupEntry {
+    let (a, b) = key_hands(key);
+    let players = [a, b];
+    let remaining_deck = (CardSet::from_cards(&a) | CardSet::from_cards(&b)).complement().to_vec();
+    let counts = enumerate_equity(&players, &[], &remaining_deck);
+    MatchupEntry {
+        key,
+        wins_a: counts.wins[0] as u32,
+        wins_b: counts.wins[1] as u32,
+    }
+}
+
+/// Serialize entries (without header) in table order
+pub fn encode_entries(entries: &[MatchupEntry]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(entries.len() * ENTRY_LEN);
+    for entry in entries {
+        bytes.extend_from_slice(&entry.key.to_le_bytes());
+        bytes.extend_from_slice(&entry.wins_a.to_le_bytes());
+        bytes.extend_from_slice(&entry.wins_b.to_le_bytes());
+    }
+    bytes
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([bytes[offset], bytes[offset + 1], bytes[offset + 2], bytes[offset + 3]])
+}
+
+/// Loaded heads-up preflop table with binary-search lookup
+pub struct PreflopTable {
+    entries: Vec<MatchupEntry>,
+}
+
+impl PreflopTable {
+    /// Build a table from entries, sorting them by key
+    pub fn from_entries(mut entries: Vec<MatchupEntry>) -> Result<PreflopTable, EquityError> {
+        entries.sort_by_key(|entry| entry.key);
+        if entries.windows(2).any(|pair| pair[0].key == pair[1].key) {
+            return Err(EquityError::PreflopTable("Preflop table has duplicate matchups".to_string()));
+        }
+        if entries.iter().any(|e| e.wins_a as u64 + e.wins_b as u64 > BOARDS_PER_MATCHUP as u64) {
+            return Err(EquityError::PreflopTable("Preflop table has invalid board counts".to_string()));
+        }
+        Ok(PreflopTable { entries })
+    }
+
+    /// Parse a table file (header + entries)
+    pub fn from_bytes(bytes: &[u8]) -> Result<PreflopTable, EquityError> {
+        if bytes.len() < HEADER_LEN || &bytes[..4] != MAGIC {
+            return Err(EquityError::PreflopTable("Not a preflop equity table".to_string()));
+        }
+        let version = u16::from_le_bytes([bytes[4], bytes[5]]);
+        if version != VERSION {
+            return Err(EquityError::PreflopTable(format!(
+                "Unsupported preflop table version: {}",
+                version
+            )));
+        }
+        let count = read_u32(bytes, 8) as usize;
+        if bytes.len() != HEADER_LEN + count * ENTRY_LEN {
+            return Err(EquityError::PreflopTable("Preflop table length does not match its header".to_string()));
+        }
+        Self::from_raw_entries(&bytes[HEADER_LEN..])
+    }
+
+    /// Parse headerless entries, e.g. concatenated generator chunks
+    pub fn from_raw_entries(bytes: &[u8]) -> Result<PreflopTable, EquityError> {
+        if !bytes.len().is_multiple_of(ENTRY_LEN) {
+            return Err(EquityError::PreflopTable("Preflop table entries are truncated".to_string()));
+        }
+        let entries = bytes
+            .chunks_exact(ENTRY_LEN)
+            .map(|chunk| MatchupEntry {
+                key: read_u32(chunk, 0),
+                wins_a: read_u32(chunk, 4),
+                wins_b: read_u32(chunk, 8),
+            })
+            .collect();
+        Self::from_entries(entries)
+    }
+
+    /// Serialize the table file (header + entries)
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN + self.entries.len() * ENTRY_LEN);
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&VERSION.to_le_bytes());
+        bytes.extend_from_slice(&0u16.to_le_bytes());
+        bytes.extend_from_slice(&(self.entries.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&encode_entries(&self.entries));
+        bytes
+    }
+
+    /// Number of canonical matchups in the table
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Exact counts for hero vs villain, in (hero, villain) order
+    ///
+    /// Returns `None` when the matchup is not in the table.
+    pub fn lookup(&self, hero: [Card; 2], villain: [Card; 2]) -> Option<EquityCounts> {
+        let (key, swapped) = canonical_key(hero, villain);
+        let idx = self.entries.binary_search_by_key(&key, |entry| entry.key).ok()?;
+        let entry = self.entries[idx];
+        let (hero_wins, villain_wins) = if swapped {
+            (entry.wins_b, entry.wins_a)
+        } else {
+            (entry.wins_a, entry.wins_b)
+        };
+
+        // Every tied board splits the pot between both players
+        let ties = BOARDS_PER_MATCHUP - hero_wins - villain_wins;
+        let mut counts = EquityCounts::new(2);
+        counts.record_weighted(&[0], hero_wins as u64);
+        counts.record_weighted(&[1], villain_wins as u64);
+        counts.record_weighted(&[0, 1], ties as u64);
+        Some(counts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exact_equity, parse_card_list};
+
+    fn hand(text: &str) -> [Card; 2] {
+        parse_card_list(text).unwrap().try_into().unwrap()
+    }
+
+    fn permuted(hand: [Card; 2], permutation: &[u8; 4]) -> [Card; 2] {
+        hand.map(|card| Card { suit: permutation[card.suit as usize], ..card })
+    }
+
+    #[test]
+    fn canonical_keys_ignore_suits_and_seat_order() {
+        let hero = hand("AsKs");
+        let villain = hand("QhQd");
+        let (key, swapped) = canonical_key(hero, villain);
+        for permutation in &SUIT_PERMUTATIONS {
+            let (h, v) = (permuted(hero, permutation), permuted(villain, permutation));
+            assert_eq!(canonical_key(h, v), (key, swapped));
+            assert_eq!(canonical_key(v, h), (key, !swapped));
+        }
+
+        // Every generated key is its own canonical form
+        for key in canonical_matchups() {
+            let (a, b) = key_hands(key);
+            assert_eq!(canonical_key(a, b), (key, false));
+        }
+    }
+
+    #[test]
+    fn table_bytes_round_trip() {
+        let entries = vec![
+            MatchupEntry { key: 900, wins_a: 10, wins_b: 20 },
+            MatchupEntry { key: 7, wins_a: BOARDS_PER_MATCHUP, wins_b: 0 },
+            MatchupEntry { key: 12_345, wins_a: 1, wins_b: 2 },
+        ];
+        let table = PreflopTable::from_raw_entries(&encode_entries(&entries)).unwrap();
+        let bytes = table.to_bytes();
+        assert_eq!(&bytes[..4], MAGIC);
+        assert_eq!(bytes.len(), HEADER_LEN + 3 * ENTRY_LEN);
+
+        let loaded = PreflopTable::from_bytes(&bytes).unwrap();
+        assert_eq!(loaded.len(), 3);
+        assert_eq!(loaded.entries.iter().map(|entry| entry.key).collect::<Vec<_>>(), [7, 900, 12_345]);
+        assert_eq!(loaded.to_bytes(), bytes);
+    }
+
+    #[test]
+    fn corrupt_tables_are_rejected() {
+        let entry = |key, wins_a, wins_b| MatchupEntry { key, wins_a, wins_b };
+        let bytes = PreflopTable::from_entries(vec![entry(1, 2, 3), entry(4, 5, 6)]).unwrap().to_bytes();
+        let rejects = |bytes: &[u8]| matches!(PreflopTable::from_bytes(bytes), Err(EquityError::PreflopTable(_)));
+
+        assert!(rejects(&bytes[..HEADER_LEN - 1]));
+        assert!(rejects(&bytes[..bytes.len() - 1]));
+        assert!(rejects(&bytes[..bytes.len() - ENTRY_LEN]));
+        assert!(rejects(&[&bytes[..], &[0; ENTRY_LEN]].concat()));
+        let mut bad_magic = bytes.clone();
+        bad_magic[0] = b'X';
+        assert!(rejects(&bad_magic));
+        let mut bad_version = bytes.clone();
+        bad_version[4] = 2;
+        assert!(rejects(&bad_version));
+
+        // Entries: truncated, duplicated, or with more wins than boards
+        assert!(PreflopTable::from_raw_entries(&bytes[HEADER_LEN..bytes.len() - 1]).is_err());
+        assert!(PreflopTable::from_entries(vec![entry(1, 2, 3), entry(1, 2, 3)]).is_err());
+        assert!(PreflopTable::from_entries(vec![entry(1, BOARDS_PER_MATCHUP, 1)]).is_err());
+    }
+
+    #[test]
+    fn lookups_match_exact_equity_for_every_suit_and_seat_order() {
+        let matchups = [("AsAh", "KsKh"), ("AsKs", "QhQd")];
+        let entries = matchups
+            .iter()
+            .map(|&(hero, villain)| compute_entry(canonical_key(hand(hero), hand(villain)).0))
+            .collect();
+        let table = PreflopTable::from_entries(entries).unwrap();
+
+        for (hero, villain) in matchups {
+            for permutation in &SUIT_PERMUTATIONS {
+                let (h, v) = (permuted(hand(hero), permutation), permuted(hand(villain), permutation));
+                let counts = table.lookup(h, v).unwrap();
+                let swapped = table.lookup(v, h).unwrap();
+                assert_eq!(counts.wins, [swapped.wins[1], swapped.wins[0]]);
+                assert_eq!(counts.ties, swapped.ties);
+                assert_eq!(counts.total, u64::from(BOARDS_PER_MATCHUP));
+            }
+            // One suit-permuted copy, with the seats swapped, enumerated exactly
+            let permutation = &SUIT_PERMUTATIONS[9];
+            let (h, v) = (permuted(hand(villain), permutation), permuted(hand(hero), permutation));
+            let counts = table.lookup(h, v).unwrap().board_counts();
+            assert_eq!(exact_equity(&[h, v], &[], &[]).unwrap().counts, Some(counts));
+        }
+        assert!(table.lookup(hand("AsAh"), hand("KsKd")).is_none());
+    }
+}
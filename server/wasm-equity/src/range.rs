@@ -0,0 +1,441 @@
+// Hand ranges in standard poker notation
+//
+// A range string is a comma (or whitespace) separated list of tokens, each with an
+// optional ":weight" suffix in [0, 1]:
+// - pairs: "TT", "TT+" (TT through AA), "99-66"
+// - suited / offsuit / both: "AKs", "AKo", "AK"
+// - kicker runs: "ATs+" (ATs through AKs), "A5s-A2s"
+// - specific combos: "AhKh"
+// Later tokens override the weight of combos listed earlier; weight 0 removes them.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use crate::card_set::{parse_rank, rank_char, CardSet, SUIT_CHARS};
+use crate::error::EquityError;
+use crate::Card;
+
+/// One specific hole-card combination and its weight
+///
+/// `cards[0]` is always the higher card (by rank, then suit).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Combo {
+    pub cards: [Card; 2],
+    pub weight: f64,
+}
+
+impl Combo {
+    /// Combo in card notation, e.g. "AhKh"
+    pub fn notation(&self) -> String {
+        let [high, low] = self.cards;
+        format!(
+            "{}{}{}{}",
+            rank_char(high.rank),
+            SUIT_CHARS[high.suit as usize],
+            rank_char(low.rank),
+            SUIT_CHARS[low.suit as usize]
+        )
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Suitedness {
+    Any,
+    Suited,
+    Offsuit,
+}
+
+// A starting-hand class such as "TT", "AKs" or "KQ" (pairs always use `Any`)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct HandClass {
+    high: u8,
+    low: u8,
+    suitedness: Suitedness,
+}
+
+impl HandClass {
+    fn is_pair(&self) -> bool {
+        self.high == self.low
+    }
+
+    // Every combo of this class, higher card first
+    fn combos(&self) -> Vec<[Card; 2]> {
+        let mut combos = Vec::with_capacity(16);
+        for high_suit in (0..4u8).rev() {
+            for low_suit in (0..4u8).rev() {
+                let suited = high_suit == low_suit;
+                let wanted = match self.suitedness {
+                    _ if self.is_pair() => high_suit > low_suit,
+                    Suitedness::Any => true,
+                    Suitedness::Suited => suited,
+                    Suitedness::Offsuit => !suited,
+                };
+                if wanted {
+                    combos.push([
+                        Card { rank: self.high, suit: high_suit },
+                        Card { rank: self.low, suit: low_suit },
+                    ]);
+                }
+            }
+        }
+        combos
+    }
+
+    fn notation(&self) -> String {
+        let suffix = match self.suitedness {
+            Suitedness::Suited => "s",
+            Suitedness::Offsuit => "o",
+            Suitedness::Any => "",
+        };
+        format!("{}{}{}", rank_char(self.high), rank_char(self.low), suffix)
+    }
+}
+
+// Put the higher card first so every combo has a single canonical form
+fn ordered(a: Card, b: Card) -> [Card; 2] {
+    if (a.rank, a.suit) > (b.rank, b.suit) {
+        [a, b]
+    } else {
+        [b, a]
+    }
+}
+
+// Parse "AK", "AKs", "AKo" or "TT"; returns the class and the unparsed remainder
+fn parse_class(text: &str) -> Option<(HandClass, &str)> {
+    let mut chars = text.chars();
+    let first = parse_rank(chars.next()?)?;
+    let second = parse_rank(chars.next()?)?;
+    let (high, low) = if first >= second { (first, second) } else { (second, first) };
+
+    let rest = chars.as_str();
+    let (suitedness, rest) = match rest.chars().next() {
+        Some('s') if high != low => (Suitedness::Suited, &rest[1..]),
+        Some('o') if high != low => (Suitedness::Offsuit, &rest[1..]),
+        _ => (Suitedness::Any, rest),
+    };
+
+    Some((HandClass { high, low, suitedness }, rest))
+}
+
+// Expand a range token body (without weight) into its hand classes or specific combos
+fn expand_token(body: &str) -> Option<Vec<[Card; 2]>> {
+    // Specific combo, e.g. "AhKh"
+    if body.chars().count() == 4 {
+        if let Ok(cards) = CardSet::parse(body) {
+            let cards = cards.to_vec();
+            return Some(vec![ordered(cards[0], cards[1])]);
+        }
+    }
+
+    let (class, rest) = parse_class(body)?;
+    let classes: Vec<HandClass> = if rest.is_empty() {
+        vec![class]
+    } else if rest == "+" {
+        if class.is_pair() {
+            // "TT+" = TT through AA
+            (class.high..=14)
+                .map(|rank| HandClass { high: rank, low: rank, ..class })
+                .collect()
+        } else {
+            // "ATs+" = ATs through AKs
+            (class.low..class.high).map(|low| HandClass { low, ..class }).collect()
+        }
+    } else {
+        // "99-66" or "A5s-A2s"
+        let (end, tail) = parse_class(rest.strip_prefix('-')?)?;
+        if !tail.is_empty() || end.is_pair() != class.is_pair() || end.suitedness != class.suitedness {
+            return None;
+        }
+        if class.is_pair() {
+            let (bottom, top) = (class.high.min(end.high), class.high.max(end.high));
+            (bottom..=top)
+                .map(|rank| HandClass { high: rank, low: rank, ..class })
+                .collect()
+        } else {
+            if end.high != class.high {
+                return None;
+            }
+            let (bottom, top) = (class.low.min(end.low), class.low.max(end.low));
+            (bottom..=top).map(|low| HandClass { low, ..class }).collect()
+        }
+    };
+
+    Some(classes.iter().flat_map(|c| c.combos()).collect())
+}
+
+/// A weighted hand range; each combo appears at most once
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Range {
+    combos: Vec<Combo>,
+}
+
+impl Range {
+    /// Parse a range string such as "TT+, AKs, A5s-A2s, KQo, 76s:0.5"
+    pub fn parse(text: &str) -> Result<Range, EquityError> {
+        let mut weights: BTreeMap<(u8, u8, u8, u8), f64> = BTreeMap::new();
+
+        for token in text.split(|c: char| c == ',' || c.is_whitespace()).filter(|t| !t.is_empty()) {
+            let (body, weight) = match token.split_once(':') {
+                Some((body, weight_text)) => {
+                    let weight: f64 = weight_text
+                        .parse()
+                        .map_err(|_| EquityError::InvalidRange(format!("Invalid range weight: {}", token)))?;
+                    if !(0.0..=1.0).contains(&weight) {
+                        return Err(EquityError::InvalidRange(format!(
+                            "Range weight must be between 0 and 1: {}",
+                            token
+                        )));
+                    }
+                    (body, weight)
+                }
+                None => (token, 1.0),
+            };
+
+            let combos = expand_token(body)
+                .ok_or_else(|| EquityError::InvalidRange(format!("Invalid range token: {}", token)))?;
+            for [high, low] in combos {
+                let key = (high.rank, high.suit, low.rank, low.suit);
+                if weight > 0.0 {
+                    weights.insert(key, weight);
+                } else {
+                    weights.remove(&key);
+                }
+            }
+        }
+
+        // Canonical order: strongest-looking combos first
+        let combos = weights
+            .into_iter()
+            .rev()
+            .map(|((r1, s1, r2, s2), weight)| Combo {
+                cards: [Card { rank: r1, suit: s1 }, Card { rank: r2, suit: s2 }],
+                weight,
+            })
+            .collect();
+        Ok(Range { combos })
+    }
+
+    /// Range holding exactly one known hand
+    pub fn from_hand(cards: [Card; 2]) -> Range {
+        Range {
+            combos: vec![Combo {
+                cards: ordered(cards[0], cards[1]),
+                weight: 1.0,
+            }],
+        }
+    }
+
+    /// Every one of the 1326 starting hands with weight 1 (an unknown/random hand)
+    pub fn any_two() -> Range {
+        let deck: Vec<Card> = CardSet::FULL_DECK.iter().rev().collect();
+        let mut combos = Vec::with_capacity(1326);
+        for (i, &high) in deck.iter().enumerate() {
+            for &low in &deck[i + 1..] {
+                combos.push(Combo { cards: [high, low], weight: 1.0 });
+            }
+        }
+        Range { combos }
+    }
+
+    /// All combos in the range, in canonical order
+    pub fn combos(&self) -> &[Combo] {
+        &self.combos
+    }
+
+    /// Total weight of the range (number of combos when every weight is 1)
+    pub fn total_weight(&self) -> f64 {
+        self.combos.iter().map(|combo| combo.weight).sum()
+    }
+
+    /// Remove combos that use any of the known board or dead cards
+    pub fn remove_blocked(&mut self, blocked: CardSet) {
+        self.combos
+            .retain(|combo| CardSet::from_cards(&combo.cards).is_disjoint(blocked));
+    }
+
+    /// Normalized range string, e.g. "QQ+, AJs+, A5s-A2s, KQo, 76s:0.5, AhKd"
+    ///
+    /// Complete hand classes with equal weights are merged into "+" and "-" runs;
+    /// partially present classes are listed combo by combo.
+    pub fn to_notation(&self) -> String {
+        let lookup: HashMap<(u8, u8, u8, u8), f64> = self
+            .combos
+            .iter()
+            .map(|c| ((c.cards[0].rank, c.cards[0].suit, c.cards[1].rank, c.cards[1].suit), c.weight))
+            .collect();
+        let mut covered: Vec<bool> = vec![false; self.combos.len()];
+        let combo_index: HashMap<(u8, u8, u8, u8), usize> = self
+            .combos
+            .iter()
+            .enumerate()
+            .map(|(i, c)| ((c.cards[0].rank, c.cards[0].suit, c.cards[1].rank, c.cards[1].suit), i))
+            .collect();
+
+        // Weight shared by every combo of a class, if the class is complete
+        let mut class_weight = |class: HandClass| -> Option<f64> {
+            let keys: Vec<(u8, u8, u8, u8)> = class
+                .combos()
+                .iter()
+                .map(|[h, l]| (h.rank, h.suit, l.rank, l.suit))
+                .collect();
+            let weight = *lookup.get(&keys[0])?;
+            if keys.iter().all(|key| lookup.get(key) == Some(&weight)) {
+                for key in &keys {
+                    covered[combo_index[key]] = true;
+                }
+                Some(weight)
+            } else {
+                None
+            }
+        };
+
+        let mut tokens: Vec<String> = Vec::new();
+
+        // Pairs, AA down to 22
+        let pairs: Vec<(HandClass, Option<f64>)> = (2..=14u8)
+            .rev()
+            .map(|rank| {
+                let class = HandClass { high: rank, low: rank, suitedness: Suitedness::Any };
+                (class, class_weight(class))
+            })
+            .collect();
+        push_runs(&mut tokens, &pairs, 14);
+
+        // Suited then offsuit classes for each high card
+        for high in (3..=14u8).rev() {
+            for suitedness in [Suitedness::Suited, Suitedness::Offsuit] {
+                let classes: Vec<(HandClass, Option<f64>)> = (2..high)
+                    .rev()
+                    .map(|low| {
+                        let class = HandClass { high, low, suitedness };
+                        (class, class_weight(class))
+                    })
+                    .collect();
+                push_runs(&mut tokens, &classes, high - 1);
+            }
+        }
+
+        // Leftover combos from partially present classes
+        for (combo, &done) in self.combos.iter().zip(covered.iter()) {
+            if !done {
+                tokens.push(with_weight(combo.notation(), combo.weight));
+            }
+        }
+
+        tokens.join(", ")
+    }
+}
+
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_notation())
+    }
+}
+
+fn with_weight(text: String, weight: f64) -> String {
+    if weight == 1.0 {
+        text
+    } else {
+        format!("{}:{}", text, weight)
+    }
+}
+
+// Merge consecutive classes (ordered strongest first) with equal weights into tokens.
+// `top` is the strongest possible class rank in the sequence, which allows the "+" form.
+fn push_runs(tokens: &mut Vec<String>, classes: &[(HandClass, Option<f64>)], top: u8) {
+    let mut start = 0;
+    while start < classes.len() {
+        let Some(weight) = classes[start].1 else {
+            start += 1;
+            continue;
+        };
+        let mut end = start;
+        while end + 1 < classes.len() && classes[end + 1].1 == Some(weight) {
+            end += 1;
+        }
+
+        let first = classes[start].0;
+        let last = classes[end].0;
+        let rank_of = |class: HandClass| if class.is_pair() { class.high } else { class.low };
+        let text = if start == end {
+            first.notation()
+        } else if rank_of(first) == top {
+            format!("{}+", last.notation())
+        } else {
+            format!("{}-{}", first.notation(), last.notation())
+        };
+        tokens.push(with_weight(text, weight));
+        start = end + 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn normalized(text: &str) -> String {
+        Range::parse(text).unwrap().to_notation()
+    }
+
+    #[test]
+    fn expands_classes_runs_and_combos() {
+        let range = Range::parse("TT+, AKs, A5s-A2s, KQo, 76s:0.5").unwrap();
+        assert_eq!(range.combos().len(), 30 + 4 + 16 + 12 + 4);
+        assert_eq!(range.total_weight(), 64.0);
+
+        assert_eq!(Range::parse("AK").unwrap().combos().len(), 16);
+        assert_eq!(Range::parse("99-66").unwrap().combos().len(), 24);
+        assert_eq!(Range::parse("ATs+").unwrap().combos().len(), 16);
+
+        let combo = Range::parse("KdAh").unwrap();
+        assert_eq!(combo.combos().len(), 1);
+        assert_eq!(combo.combos()[0].notation(), "AhKd");
+    }
+
+    #[test]
+    fn later_tokens_override_weights() {
+        let range = Range::parse("AK, AKs:0.5, AhKh:0").unwrap();
+        assert_eq!(range.combos().len(), 15);
+        assert_eq!(range.total_weight(), 12.0 + 1.5);
+        assert_eq!(range.to_notation(), "AKo, AsKs:0.5, AdKd:0.5, AcKc:0.5");
+    }
+
+    #[test]
+    fn normalizes_to_runs() {
+        assert_eq!(normalized("AA, KK, QQ, JJ, TT"), "TT+");
+        assert_eq!(normalized("A2s A3s A4s A5s"), "A5s-A2s");
+        assert_eq!(normalized("AKs, AQs, AJs, KQo"), "AJs+, KQo");
+        assert_eq!(normalized("99-66:0.25, 22"), "99-66:0.25, 22");
+    }
+
+    #[test]
+    fn notation_round_trips() {
+        for text in [
+            "TT+, AKs, A5s-A2s, KQo, 76s:0.5",
+            "QQ+, AJs+, KQo, AhKd",
+            "22+, A2+, K9s+, T8o:0.3, 5h4h",
+            "AK, AKs:0.5, AhKh:0",
+        ] {
+            let range = Range::parse(text).unwrap();
+            let notation = range.to_notation();
+            assert_eq!(Range::parse(&notation).unwrap(), range, "{} -> {}", text, notation);
+            assert_eq!(normalized(&notation), notation);
+        }
+        let any_two = Range::any_two();
+        assert_eq!(Range::parse(&any_two.to_notation()).unwrap(), any_two);
+    }
+
+    #[test]
+    fn removes_blocked_combos() {
+        let mut range = Range::parse("AA, AKs").unwrap();
+        range.remove_blocked(CardSet::parse("Ah").unwrap());
+        assert_eq!(range.combos().len(), 3 + 3);
+    }
+
+    #[test]
+    fn rejects_invalid_tokens_and_weights() {
+        for text in ["AKx", "A5s-K2s", "ZZ", "AK:1.5", "AK:x", "AhAh"] {
+            assert!(Range::parse(text).is_err(), "{}", text);
+        }
+    }
+}
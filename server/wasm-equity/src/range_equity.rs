@@ -0,0 +1,397 @@
+// Range-vs-range equity
+//
+// Every player holds a weighted range. Combo tuples that share a card are impossible
+// and skipped; the others count with the product of their combo weights. Small
+// problems are enumerated exactly (every tuple x every board completion), larger
+// ones are sampled with the seeded generator.
+
+use crate::card_set::CardSet;
+use crate::error::EquityError;
+use crate::range::{Combo, Range};
+use crate::{enumerate_equity, equity_std_errors, Card, Rng, Showdown};
+
+// Largest number of (tuple, board) evaluations enumerated exactly before sampling instead
+const MAX_EXACT_EVALUATIONS: f64 = 20_000_000.0;
+
+// Consecutive conflicting combo tuples before the ranges are declared incompatible
+const MAX_TUPLE_ATTEMPTS: u32 = 100_000;
+
+/// Equity of one hero combo against the opponent ranges
+///
+/// `equity` is `None` when the combo never met a non-overlapping opponent tuple.
+pub struct ComboEquity {
+    pub combo: Combo,
+    pub equity: Option<f64>,
+}
+
+/// Result of a range-vs-range calculation (player 0 is the hero)
+pub struct RangeEquity {
+    pub win: Vec<f64>,
+    pub tie: Vec<f64>,
+    pub lose: Vec<f64>,
+    pub tie_probability: Vec<f64>,
+    pub chops: Vec<Vec<f64>>, // chops[player][n]: probability of an n-way split
+    pub samples: u64, // boards evaluated
+    pub exact: bool,
+    pub std_error: Option<Vec<f64>>, // sampled runs only
+    pub hero_combos: Vec<ComboEquity>,
+}
+
+// Weighted counters shared by the exact and sampled paths
+struct RangeAccumulator {
+    win: Vec<f64>,
+    tie: Vec<f64>,
+    chops: Vec<Vec<f64>>, // weight of the boards each player split n ways
+    total_weight: f64,
+    samples: u64,
+    hero_share: Vec<f64>,
+    hero_weight: Vec<f64>,
+    share_squares: Vec<f64>, // sampled path: per-player sum of squared pot shares
+}
+
+impl RangeAccumulator {
+    fn new(num_players: usize, hero_combos: usize) -> Self {
+        RangeAccumulator {
+            win: vec![0.0; num_players],
+            tie: vec![0.0; num_players],
+            chops: vec![vec![0.0; num_players + 1]; num_players],
+            total_weight: 0.0,
+            samples: 0,
+            hero_share: vec![0.0; hero_combos],
+            hero_weight: vec![0.0; hero_combos],
+            share_squares: vec![0.0; num_players],
+        }
+    }
+}
+
+// Number of k-card subsets of n cards, as f64 (only used for the feasibility estimate)
+fn combinations(n: usize, k: usize) -> f64 {
+    if k > n {
+        return 0.0;
+    }
+    (0..k).fold(1.0, |acc, i| acc * (n - i) as f64 / (i + 1) as f64)
+}
+
+// True if any card of `combo` is already held by another player in the tuple
+fn conflicts(combo: &Combo, used: CardSet) -> bool {
+    combo.cards.iter().any(|&card| used.contains(card))
+}
+
+// Every combo tuple shares a card between two players
+fn no_compatible_combos() -> EquityError {
+    EquityError::InvalidRange("Ranges have no non-overlapping combinations".to_string())
+}
+
+/// Equity of `ranges[0]` (hero) against `ranges[1..]` on a partial board
+///
+/// Ranges must already have board and dead cards removed; `remaining_deck` is the
+/// deck without board and dead cards. Exact when the tuple x board count is small
+/// enough, otherwise `samples` random (tuple, board) draws.
+pub fn range_equity(
+    ranges: &[Range],
+    known_board: &[Card],
+    remaining_deck: &[Card],
+    samples: u64,
+    rng: &mut Rng,
+) -> Result<RangeEquity, EquityError> {
+    let num_players = ranges.len();
+    if let Some(empty) = ranges.iter().position(|range| range.combos().is_empty()) {
+        return Err(EquityError::InvalidRange(format!(
+            "Range for player {} has no combos left after removing blocked cards",
+            empty + 1
+        )));
+    }
+
+    let missing = 5 - known_board.len();
+    let deck_after_holes = remaining_deck.len().saturating_sub(2 * num_players);
+    if deck_after_holes < missing {
+        return Err(EquityError::InsufficientDeck {
+            needed: missing,
+            available: deck_after_holes,
+        });
+    }
+
+    let tuples: f64 = ranges.iter().map(|range| range.combos().len() as f64).product();
+    let exact = tuples * combinations(deck_after_holes, missing) <= MAX_EXACT_EVALUATIONS;
+
+    let mut acc = RangeAccumulator::new(num_players, ranges[0].combos().len());
+    if exact {
+        let mut chosen = Vec::with_capacity(num_players);
+        enumerate_tuples(ranges, known_board, remaining_deck, &mut chosen, CardSet::EMPTY, 1.0, &mut acc);
+    } else {
+        if samples == 0 {
+            return Err(EquityError::InvalidArgument("Sample count must be positive".to_string()));
+        }
+        sample_tuples(ranges, known_board, remaining_deck, samples, rng, &mut acc)?;
+    }
+
+    if acc.total_weight == 0.0 {
+        return Err(no_compatible_combos());
+    }
+
+    let win: Vec<f64> = acc.win.iter().map(|&w| w / acc.total_weight).collect();
+    let tie: Vec<f64> = acc.tie.iter().map(|&t| t / acc.total_weight).collect();
+    let lose: Vec<f64> = win.iter().zip(tie.iter()).map(|(&w, &t)| 1.0 - w - t).collect();
+    let chops: Vec<Vec<f64>> = acc
+        .chops
+        .iter()
+        .map(|splits| splits.iter().map(|&s| s / acc.total_weight).collect())
+        .collect();
+    let tie_probability = chops.iter().map(|splits| splits.iter().sum()).collect();
+    let std_error = (!exact).then(|| equity_std_errors(acc.samples, &acc.share_squares, &win, &tie));
+    let hero_combos = ranges[0]
+        .combos()
+        .iter()
+        .zip(acc.hero_share.iter().zip(acc.hero_weight.iter()))
+        .map(|(&combo, (&share, &weight))| ComboEquity {
+            combo,
+            equity: if weight > 0.0 { Some(share / weight) } else { None },
+        })
+        .collect();
+
+    Ok(RangeEquity {
+        win,
+        tie,
+        lose,
+        tie_probability,
+        chops,
+        samples: acc.samples,
+        exact,
+        std_error,
+        hero_combos,
+    })
+}
+
+// Depth-first over players: pick every non-conflicting combo, then enumerate boards
+fn enumerate_tuples(
+    ranges: &[Range],
+    known_board: &[Card],
+    remaining_deck: &[Card],
+    chosen: &mut Vec<usize>,
+    used: CardSet,
+    weight: f64,
+    acc: &mut RangeAccumulator,
+) {
+    let player = chosen.len();
+    if player == ranges.len() {
+        let players: Vec<[Card; 2]> = chosen
+            .iter()
+            .zip(ranges.iter())
+            .map(|(&idx, range)| range.combos()[idx].cards)
+            .collect();
+        let deck: Vec<Card> = remaining_deck
+            .iter()
+            .copied()
+            .filter(|&card| !used.contains(card))
+            .collect();
+        let counts = enumerate_equity(&players, known_board, &deck);
+
+        for p in 0..players.len() {
+            acc.win[p] += weight * counts.wins[p] as f64;
+            acc.tie[p] += weight * counts.tie_pots(p);
+            for (split, &count) in acc.chops[p].iter_mut().zip(counts.player_ties(p)) {
+                *split += weight * count as f64;
+            }
+        }
+        let boards = counts.total as f64;
+        acc.total_weight += weight * boards;
+        acc.samples += counts.total;
+        acc.hero_share[chosen[0]] += weight * (counts.wins[0] as f64 + counts.tie_pots(0));
+        acc.hero_weight[chosen[0]] += weight * boards;
+        return;
+    }
+
+    for (idx, combo) in ranges[player].combos().iter().enumerate() {
+        if conflicts(combo, used) {
+            continue;
+        }
+        chosen.push(idx);
+        let used = used.with(combo.cards[0]).with(combo.cards[1]);
+        enumerate_tuples(ranges, known_board, remaining_deck, chosen, used, weight * combo.weight, acc);
+        chosen.pop();
+    }
+}
+
+// Pick a combo index with probability proportional to its weight
+fn pick_weighted(cumulative: &[f64], rng: &mut Rng) -> usize {
+    let target = rng.next_f64() * cumulative[cumulative.len() - 1];
+    cumulative
+        .partition_point(|&c| c <= target)
+        .min(cumulative.len() - 1)
+}
+
+// Rejection-sample non-conflicting combo tuples by weight, then a random board for each
+fn sample_tuples(
+    ranges: &[Range],
+    known_board: &[Card],
+    remaining_deck: &[Card],
+    samples: u64,
+    rng: &mut Rng,
+    acc: &mut RangeAccumulator,
+) -> Result<(), EquityError> {
+    let num_players = ranges.len();
+    let known = known_board.len();
+    let missing = 5 - known;
+
+    let cumulative: Vec<Vec<f64>> = ranges
+        .iter()
+        .map(|range| {
+            range
+                .combos()
+                .iter()
+                .scan(0.0, |sum, combo| {
+                    *sum += combo.weight;
+                    Some(*sum)
+                })
+                .collect()
+        })
+        .collect();
+
+    let mut deck = remaining_deck.to_vec();
+    let deck_len = deck.len() as u32;
+    let mut complete_board = [Card { rank: 0, suit: 0 }; 5];
+    complete_board[..known].copy_from_slice(known_board);
+    let mut players = vec![[Card { rank: 0, suit: 0 }; 2]; num_players];
+    let mut chosen = vec![0usize; num_players];
+    let mut used;
+    let mut showdown = Showdown::new(num_players);
+
+    for _ in 0..samples {
+        // Draw one combo per player; start over if any two share a card
+        let mut attempts = 0;
+        'tuple: loop {
+            attempts += 1;
+            if attempts > MAX_TUPLE_ATTEMPTS {
+                return Err(no_compatible_combos());
+            }
+            used = CardSet::EMPTY;
+            for (p, range) in ranges.iter().enumerate() {
+                let idx = pick_weighted(&cumulative[p], rng);
+                let combo = &range.combos()[idx];
+                if conflicts(combo, used) {
+                    continue 'tuple;
+                }
+                chosen[p] = idx;
+                players[p] = combo.cards;
+                used = used.with(combo.cards[0]).with(combo.cards[1]);
+            }
+            break;
+        }
+
+        // Draw the missing board cards, redrawing any card held by a player
+        for slot in 0..missing {
+            loop {
+                let pick = slot + rng.below(deck_len - slot as u32) as usize;
+                deck.swap(slot, pick);
+                if !used.contains(deck[slot]) {
+                    break;
+                }
+            }
+            complete_board[known + slot] = deck[slot];
+        }
+
+        let winners = showdown.winners(&players, &complete_board);
+        let share = 1.0 / winners.len() as f64;
+        for &winner in winners {
+            acc.share_squares[winner] += share * share;
+        }
+        if winners.len() > 1 {
+            for &winner in winners {
+                acc.tie[winner] += share;
+                acc.chops[winner][winners.len()] += 1.0;
+            }
+        } else {
+            acc.win[winners[0]] += 1.0;
+        }
+        if winners.contains(&0) {
+            acc.hero_share[chosen[0]] += share;
+        }
+        acc.hero_weight[chosen[0]] += 1.0;
+        acc.total_weight += 1.0;
+        acc.samples += 1;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exact_equity, parse_card_list, range_vs_range_equity};
+
+    // Weighted mean of the hero's exact equity over every non-overlapping combo pair,
+    // and the hero's exact equity per combo
+    fn brute_force(hero: &str, villain: &str, board: &[Card]) -> (f64, Vec<f64>) {
+        let blocked = CardSet::from_cards(board);
+        let [mut hero, mut villain] = [hero, villain].map(|text| Range::parse(text).unwrap());
+        hero.remove_blocked(blocked);
+        villain.remove_blocked(blocked);
+
+        let (mut share, mut weight) = (0.0, 0.0);
+        let mut per_combo = Vec::new();
+        for hero_combo in hero.combos() {
+            let (mut combo_share, mut combo_weight) = (0.0, 0.0);
+            for villain_combo in villain.combos() {
+                if conflicts(villain_combo, CardSet::from_cards(&hero_combo.cards)) {
+                    continue;
+                }
+                let result = exact_equity(&[hero_combo.cards, villain_combo.cards], board, &[]).unwrap();
+                combo_share += villain_combo.weight * (result.win[0] + result.tie[0]);
+                combo_weight += villain_combo.weight;
+            }
+            share += hero_combo.weight * combo_share;
+            weight += hero_combo.weight * combo_weight;
+            per_combo.push(combo_share / combo_weight);
+        }
+        (share / weight, per_combo)
+    }
+
+    #[test]
+    fn exact_ranges_match_the_weighted_combo_sum() {
+        let board = parse_card_list("2c 7d 9h").unwrap();
+        for (hero, villain) in [("AA,KK", "QQ"), ("AA,KK:0.5", "QQ,AKs:0.25"), ("9h8h,JJ", "77:0.3,T8s")] {
+            let result = range_vs_range_equity(hero, &[villain], &board, &[], 1_000, 1).unwrap();
+            assert_eq!(result.equity.exact, Some(true));
+
+            let (expected, per_combo) = brute_force(hero, villain, &board);
+            assert!((result.equity.win[0] + result.equity.tie[0] - expected).abs() < 1e-12);
+            for (combo, expected) in result.hero_combos.iter().zip(per_combo) {
+                assert!((combo.equity.unwrap() - expected).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn sampled_ranges_land_within_their_interval_of_the_exact_equity() {
+        let board = parse_card_list("2c 7d 9h").unwrap();
+        let deck = CardSet::from_cards(&board).complement().to_vec();
+        let ranges: Vec<Range> = ["AA,KK:0.5", "QQ,AK:0.5"]
+            .iter()
+            .map(|text| Range::parse(text).unwrap())
+            .collect();
+        let exact = range_equity(&ranges, &board, &deck, 0, &mut Rng::new(0)).unwrap();
+        assert!(exact.exact);
+
+        // The sampled path directly: these ranges are small enough to be enumerated
+        let samples = 40_000;
+        let mut acc = RangeAccumulator::new(2, ranges[0].combos().len());
+        sample_tuples(&ranges, &board, &deck, samples, &mut Rng::new(5), &mut acc).unwrap();
+        assert_eq!(acc.samples, samples);
+
+        // A pot share in [0, 1] has variance at most mean * (1 - mean)
+        let equity = exact.win[0] + exact.tie[0];
+        let sampled = (acc.win[0] + acc.tie[0]) / acc.total_weight;
+        let half_width = 1.96 * (equity * (1.0 - equity) / samples as f64).sqrt();
+        assert!((sampled - equity).abs() <= half_width);
+    }
+
+    #[test]
+    fn sampling_gives_up_on_ranges_that_always_overlap() {
+        let ranges = [Range::parse("AsAh").unwrap(), Range::parse("AsAd").unwrap()];
+        let deck = CardSet::FULL_DECK.to_vec();
+        let mut acc = RangeAccumulator::new(2, 1);
+        let result = sample_tuples(&ranges, &[], &deck, 10, &mut Rng::new(1), &mut acc);
+        assert_eq!(result, Err(no_compatible_combos()));
+        assert!(range_equity(&ranges, &[], &deck, 10, &mut Rng::new(1)).is_err());
+    }
+}
@@ -0,0 +1,212 @@
+// Typed results of the core functions, also returned to JavaScript
+//
+// Every wasm export returns one of these structs as a plain JS object (serialized with
+// serde-wasm-bindgen) instead of a JSON string. With the `wasm` feature their TypeScript
+// declarations are generated into the package's .d.ts and match the interfaces in
+// common/src/interfaces (`EquityResult`, `OutCard`, `OutsSuppressionReason`, `HandRank`
+// as `HandValue`, and `CalculateOutsResponse` without its server-side `*_cards` fields).
+
+use serde::Serialize;
+#[cfg(feature = "wasm")]
+use tsify::Tsify;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+/// Equity per player (fractions in [0, 1]) over `samples` board completions
+///
+/// `tie` is the expected pot share a player gets from split pots (a 3-way split adds a
+/// third of a pot); how often they split at all is `tie_probability`.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "wasm", derive(Tsify))]
+pub struct EquityResult {
+    pub win: Vec<f64>,
+    pub tie: Vec<f64>,
+    pub lose: Vec<f64>,
+    pub samples: u64,
+    // Probability of splitting the pot, and chops[player][n]: probability of splitting
+    // it n ways (`n` up to the number of players; entries 0 and 1 are always 0)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "wasm", tsify(optional))]
+    pub tie_probability: Option<Vec<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "wasm", tsify(optional))]
+    pub chops: Option<Vec<Vec<f64>>>,
+    // Random opponents and ranges: whether the result was enumerated or sampled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "wasm", tsify(optional))]
+    pub exact: Option<bool>,
+    // Sampling modes: standard error and 95% confidence interval of each equity
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "wasm", tsify(optional))]
+    pub std_error: Option<Vec<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "wasm", tsify(optional))]
+    pub ci95: Option<Vec<[f64; 2]>>,
+    // Adaptive mode: the limit that stopped sampling
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "wasm", tsify(optional))]
+    pub stop_reason: Option<StopReason>,
+    // Exact, sampled and preflop table results: the integer counts behind the fractions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "wasm", tsify(optional))]
+    pub counts: Option<BoardCounts>,
+}
+
+impl EquityResult {
+    pub fn new(win: Vec<f64>, tie: Vec<f64>, lose: Vec<f64>, samples: u64) -> Self {
+        EquityResult {
+            win,
+            tie,
+            lose,
+            samples,
+            tie_probability: None,
+            chops: None,
+            exact: None,
+            std_error: None,
+            ci95: None,
+            stop_reason: None,
+            counts: None,
+        }
+    }
+}
+
+/// Boards (or sampled boards) each player won outright or split
+///
+/// `ties[player][n]` counts the boards the player split n ways (`n` up to the number
+/// of players; entries 0 and 1 are always 0), so `tie[player]` is exactly
+/// `sum(ties[player][n] / n) / samples` and `win[player]` is `wins[player] / samples`.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "wasm", derive(Tsify))]
+pub struct BoardCounts {
+    pub wins: Vec<u64>,
+    pub ties: Vec<Vec<u64>>,
+}
+
+/// Which adaptive sampling limit was reached first
+#[derive(Serialize, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "wasm", derive(Tsify))]
+#[serde(rename_all = "lowercase")]
+pub enum StopReason {
+    Precision, // every confidence half-width is within the target
+    Time,      // the time budget ran out
+    Samples,   // the sample cap was reached
+}
+
+/// Hero's equity with one combo of their range (null if it never met an opponent hand)
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "wasm", derive(Tsify))]
+pub struct HeroComboEquity {
+    pub combo: String,
+    pub weight: f64,
+    pub equity: Option<f64>,
+}
+
+/// Range vs range equity: player 0 is the hero
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "wasm", derive(Tsify))]
+pub struct RangeEquityResult {
+    #[serde(flatten)]
+    pub equity: EquityResult,
+    pub hero_combos: Vec<HeroComboEquity>,
+}
+
+/// A range printed back in normalized notation
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "wasm", derive(Tsify))]
+pub struct NormalizedRange {
+    pub range: String,
+    pub combos: usize,
+    pub weighted_combos: f64,
+}
+
+/// One river card that makes the hero win or tie
+#[derive(Serialize, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "wasm", derive(Tsify))]
+pub struct OutCard {
+    pub rank: u8,
+    pub suit: u8, // 0=c, 1=d, 2=h, 3=s
+    pub category: u8,
+}
+
+/// Why outs are not shown, with the baseline that triggered it
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "wasm", derive(Tsify))]
+pub struct OutsSuppressionReason {
+    pub reason: String,
+    pub baseline_win: f64,
+    pub baseline_tie: f64,
+}
+
+/// Hero's outs on the turn against one villain
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "wasm", derive(Tsify))]
+pub struct OutsResult {
+    pub suppressed: Option<OutsSuppressionReason>,
+    pub win_outs: Vec<OutCard>,
+    pub tie_outs: Vec<OutCard>,
+    pub baseline_win: f64,
+    pub baseline_tie: f64,
+    pub baseline_lose: f64,
+    pub total_river_cards: usize,
+}
+
+/// Category (0 = high card ... 9 = royal flush) and tiebreak ranks of a 5-7 card hand
+///
+/// Hands compare by category, then tiebreak ranks left to right. Same shape and
+/// values as `HandRank` in common/src/interfaces/handInterfaces.ts.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "wasm", derive(Tsify))]
+pub struct HandValue {
+    pub category: u8,
+    pub tiebreak: Vec<u8>,
+    // `best_hand` only: indices into the input of the five cards that make the hand
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "wasm", tsify(optional))]
+    pub best_five: Option<Vec<usize>>,
+}
+
+/// Progress of an `EquityJob`
+#[cfg(feature = "wasm")]
+#[derive(Serialize, Tsify, Debug, Clone, PartialEq)]
+pub struct EquityJobStatus {
+    pub boards: u64,
+    pub total_boards: u64,
+    pub progress: f64,
+    pub done: bool,
+    pub cancelled: bool,
+}
+
+/// Equity over the boards an `EquityJob` has visited, with its status
+#[cfg(feature = "wasm")]
+#[derive(Serialize, Tsify, Debug, Clone, PartialEq)]
+pub struct EquityJobResult {
+    #[serde(flatten)]
+    pub equity: EquityResult,
+    pub progress: f64,
+    pub done: bool,
+    pub cancelled: bool,
+}
+
+/// A loaded preflop table
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "wasm", derive(Tsify))]
+pub struct PreflopTableInfo {
+    pub matchups: usize,
+}
+
+/// The selected evaluator backend
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "wasm", derive(Tsify))]
+pub struct EvaluatorBackendInfo {
+    pub backend: String,
+}
+
+/// Convert a result to a plain JS object
+///
+/// JSON-compatible: `None` becomes null (not undefined) and maps become objects.
+#[cfg(feature = "wasm")]
+pub fn to_js<T: Serialize>(value: &T) -> Result<JsValue, JsError> {
+    value
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(|error| JsError::new(&error.to_string()))
+}
@@ -0,0 +1,152 @@
+// wasm simd128 showdowns for the table evaluator
+//
+// Players are packed eight to a 128-bit vector, one 16-bit lane each. Per board the
+// flush check (fold each hole card of the flush suit into the board's flush ranks and
+// count them) runs for all lanes at once; each lane then needs one table read (the
+// flush or the rank-multiset table, which wasm cannot gather), and the winners come
+// from a vector max and equality mask instead of a compare per player. The hole pair
+// column of the rank-multiset table does not depend on the board and is computed once.
+//
+// Only compiled with the `simd` feature for a wasm32 target with simd128 enabled;
+// every other build scores players one at a time.
+
+use core::arch::wasm32::*;
+
+use crate::table_evaluator::{hole_pair_index, TableBoard};
+use crate::Card;
+
+const LANES: usize = 8;
+
+// Suit of unused lanes: never the flush suit
+const NO_SUIT: u16 = 4;
+
+// Up to eight players, one per lane
+struct LaneChunk {
+    suits: [v128; 2],     // suit of each hole card
+    rank_bits: [v128; 2], // 1 << rank of each hole card
+    pairs: [usize; LANES], // hole pair column in the rank-multiset table
+    len: usize,
+}
+
+/// Every player's hole cards in vector lanes, plus scratch strengths per board
+pub struct PlayerLanes {
+    players: Vec<[Card; 2]>,
+    chunks: Vec<LaneChunk>,
+    strengths: Vec<v128>, // per chunk, 0 in unused lanes
+}
+
+#[inline(always)]
+fn from_lanes(lanes: [u16; LANES]) -> v128 {
+    u16x8(lanes[0], lanes[1], lanes[2], lanes[3], lanes[4], lanes[5], lanes[6], lanes[7])
+}
+
+#[inline(always)]
+fn to_lanes(vector: v128) -> [u16; LANES] {
+    [
+        u16x8_extract_lane::<0>(vector),
+        u16x8_extract_lane::<1>(vector),
+        u16x8_extract_lane::<2>(vector),
+        u16x8_extract_lane::<3>(vector),
+        u16x8_extract_lane::<4>(vector),
+        u16x8_extract_lane::<5>(vector),
+        u16x8_extract_lane::<6>(vector),
+        u16x8_extract_lane::<7>(vector),
+    ]
+}
+
+impl PlayerLanes {
+    pub fn new(players: &[[Card; 2]]) -> Self {
+        let chunks: Vec<LaneChunk> = players
+            .chunks(LANES)
+            .map(|chunk| {
+                let mut suits = [[NO_SUIT; LANES]; 2];
+                let mut rank_bits = [[0u16; LANES]; 2];
+                let mut pairs = [0usize; LANES];
+                for (lane, hole) in chunk.iter().enumerate() {
+                    for (card_idx, card) in hole.iter().enumerate() {
+                        suits[card_idx][lane] = card.suit as u16;
+                        rank_bits[card_idx][lane] = 1 << card.rank;
+                    }
+                    pairs[lane] = hole_pair_index(hole);
+                }
+                LaneChunk {
+                    suits: [from_lanes(suits[0]), from_lanes(suits[1])],
+                    rank_bits: [from_lanes(rank_bits[0]), from_lanes(rank_bits[1])],
+                    pairs,
+                    len: chunk.len(),
+                }
+            })
+            .collect();
+        let strengths = vec![u16x8_splat(0); chunks.len()];
+
+        PlayerLanes {
+            players: players.to_vec(),
+            chunks,
+            strengths,
+        }
+    }
+
+    /// True if the lanes were built for exactly these players
+    #[inline(always)]
+    pub fn holds(&self, players: &[[Card; 2]]) -> bool {
+        self.players == players
+    }
+
+    /// Write the winning seats (ascending) on a complete board to `winners`, returning
+    /// how many there are
+    ///
+    /// Same result as comparing `TableBoard::evaluate` of every player.
+    #[inline(always)]
+    pub fn winners(&mut self, board: &TableBoard, winners: &mut [usize]) -> usize {
+        let mut best = u16x8_splat(0);
+        for (chunk, strengths) in self.chunks.iter().zip(self.strengths.iter_mut()) {
+            let mut lane_strengths = [0u16; LANES];
+            match board.flush() {
+                Some((suit, board_bits)) => {
+                    let suit = u16x8_splat(suit as u16);
+                    let hole_bits = v128_or(
+                        v128_and(u16x8_eq(chunk.suits[0], suit), chunk.rank_bits[0]),
+                        v128_and(u16x8_eq(chunk.suits[1], suit), chunk.rank_bits[1]),
+                    );
+                    let bits = v128_or(u16x8_splat(board_bits), hole_bits);
+                    // Per-lane popcount: count each byte, then add the byte pairs
+                    let counts = u16x8_extadd_pairwise_u8x16(u8x16_popcnt(bits));
+                    let flush_lanes = u16x8_bitmask(u16x8_gt(counts, u16x8_splat(4)));
+
+                    let lane_bits = to_lanes(bits);
+                    for (lane, strength) in lane_strengths.iter_mut().enumerate().take(chunk.len) {
+                        *strength = if flush_lanes & (1 << lane) != 0 {
+                            board.flush_strength(lane_bits[lane])
+                        } else {
+                            board.pair_strength(chunk.pairs[lane])
+                        };
+                    }
+                }
+                None => {
+                    for (strength, &pair) in lane_strengths.iter_mut().zip(chunk.pairs.iter()).take(chunk.len) {
+                        *strength = board.pair_strength(pair);
+                    }
+                }
+            }
+            *strengths = from_lanes(lane_strengths);
+            best = u16x8_max(best, *strengths);
+        }
+
+        // Horizontal max: fold the halves, quarters and pairs of lanes together
+        best = u16x8_max(best, i16x8_shuffle::<4, 5, 6, 7, 0, 1, 2, 3>(best, best));
+        best = u16x8_max(best, i16x8_shuffle::<2, 3, 0, 1, 6, 7, 4, 5>(best, best));
+        best = u16x8_max(best, i16x8_shuffle::<1, 0, 3, 2, 5, 4, 7, 6>(best, best));
+
+        // Strengths start at 1, so unused lanes (0) never match the best
+        let mut winner_count = 0;
+        for (chunk_idx, &strengths) in self.strengths.iter().enumerate() {
+            let mut mask = u16x8_bitmask(u16x8_eq(strengths, best));
+            while mask != 0 {
+                winners[winner_count] = chunk_idx * LANES + mask.trailing_zeros() as usize;
+                winner_count += 1;
+                mask &= mask - 1;
+            }
+        }
+        winner_count
+    }
+}
@@ -0,0 +1,174 @@
+// Suit-isomorphism reduction for board enumeration
+//
+// A suit permutation that maps every player's hole cards, the known board and the
+// remaining deck onto themselves (as sets) cannot change any showdown. Boards related
+// by such permutations therefore have identical outcomes: the enumerator evaluates
+// only the canonical board of each group (smallest card mask) and weights it by the
+// group size. Every board is still visited to find out whether it is canonical; the
+// saving is the showdown evaluation of the other members of its group.
+
+use crate::card_set::CardSet;
+use crate::Card;
+
+/// All 24 permutations of the four suits (c, d, h, s)
+pub const SUIT_PERMUTATIONS: [[u8; 4]; 24] = [
+    [0, 1, 2, 3], [0, 1, 3, 2], [0, 2, 1, 3], [0, 2, 3, 1], [0, 3, 1, 2], [0, 3, 2, 1],
+    [1, 0, 2, 3], [1, 0, 3, 2], [1, 2, 0, 3], [1, 2, 3, 0], [1, 3, 0, 2], [1, 3, 2, 0],
+    [2, 0, 1, 3], [2, 0, 3, 1], [2, 1, 0, 3], [2, 1, 3, 0], [2, 3, 0, 1], [2, 3, 1, 0],
+    [3, 0, 1, 2], [3, 0, 2, 1], [3, 1, 0, 2], [3, 1, 2, 0], [3, 2, 0, 1], [3, 2, 1, 0],
+];
+
+// The clubs of every rank in a rank-major card mask; shift by the suit for the others
+const SUIT_LANE: u64 = 0x0001_1111_1111_1111;
+
+// Apply a suit permutation to a card set by moving each suit's lane
+#[inline(always)]
+fn permute(cards: CardSet, permutation: &[u8; 4]) -> u64 {
+    let mask = cards.bits();
+    let mut image = 0;
+    for (suit, &target) in permutation.iter().enumerate() {
+        image |= ((mask >> suit) & SUIT_LANE) << target;
+    }
+    image
+}
+
+/// Suit permutations that leave a deal unchanged
+pub struct SuitSymmetry {
+    permutations: Vec<[u8; 4]>, // always includes the identity
+}
+
+impl SuitSymmetry {
+    /// Permutations fixing each player's hole cards, the known board and the remaining deck
+    pub fn new(players: &[[Card; 2]], known_board: &[Card], remaining_deck: &[Card]) -> Self {
+        let mut fixed_sets: Vec<CardSet> = players.iter().map(|hole| CardSet::from_cards(hole)).collect();
+        fixed_sets.push(CardSet::from_cards(known_board));
+        fixed_sets.push(CardSet::from_cards(remaining_deck));
+
+        let permutations = SUIT_PERMUTATIONS
+            .iter()
+            .filter(|permutation| {
+                fixed_sets
+                    .iter()
+                    .all(|&cards| permute(cards, permutation) == cards.bits())
+            })
+            .copied()
+            .collect();
+        SuitSymmetry { permutations }
+    }
+
+    /// True if only the identity fixes the deal (no reduction possible)
+    pub fn is_trivial(&self) -> bool {
+        self.permutations.len() == 1
+    }
+
+    /// Number of boards equivalent to `board` if it is the canonical (smallest mask)
+    /// member of its group, 0 otherwise
+    #[inline(always)]
+    pub fn orbit_size(&self, board: CardSet) -> u64 {
+        let board_mask = board.bits();
+        let mut stabilizer = 0;
+        for permutation in &self.permutations {
+            let image = permute(board, permutation);
+            if image < board_mask {
+                return 0;
+            }
+            if image == board_mask {
+                stabilizer += 1;
+            }
+        }
+        self.permutations.len() as u64 / stabilizer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{enumerate_equity, evaluate_7_card_hand, parse_card_list, EquityCounts};
+
+    fn cards(text: &str) -> Vec<Card> {
+        parse_card_list(text).unwrap()
+    }
+
+    fn holes(texts: &[&str]) -> Vec<[Card; 2]> {
+        texts.iter().map(|text| cards(text).try_into().unwrap()).collect()
+    }
+
+    // Evaluate every board completion without suit reduction or threads
+    fn brute_force(players: &[[Card; 2]], known_board: &[Card], remaining_deck: &[Card]) -> EquityCounts {
+        fn deal(
+            players: &[[Card; 2]],
+            board: &mut Vec<Card>,
+            deck: &[Card],
+            counts: &mut EquityCounts,
+        ) {
+            if board.len() == 5 {
+                let complete: [Card; 5] = board[..].try_into().unwrap();
+                let ranks: Vec<_> = players.iter().map(|hole| evaluate_7_card_hand(hole, &complete)).collect();
+                let best = *ranks.iter().max().unwrap();
+                let winners: Vec<usize> = (0..players.len()).filter(|&p| ranks[p] == best).collect();
+                counts.record(&winners);
+                return;
+            }
+            for (idx, &card) in deck.iter().enumerate() {
+                board.push(card);
+                deal(players, board, &deck[idx + 1..], counts);
+                board.pop();
+            }
+        }
+
+        let mut counts = EquityCounts::new(players.len());
+        deal(players, &mut known_board.to_vec(), remaining_deck, &mut counts);
+        counts
+    }
+
+    fn assert_matches_brute_force(players: &[&str], board: &str, reduced: bool) {
+        let players = holes(players);
+        let board = cards(board);
+        let known: Vec<Card> = players.iter().flatten().chain(board.iter()).copied().collect();
+        let deck = CardSet::from_cards(&known).complement().to_vec();
+        assert_eq!(!SuitSymmetry::new(&players, &board, &deck).is_trivial(), reduced);
+
+        let expected = brute_force(&players, &board, &deck);
+        let counts = enumerate_equity(&players, &board, &deck);
+        assert_eq!(counts.wins, expected.wins);
+        assert_eq!(counts.ties, expected.ties);
+        assert_eq!(counts.total, expected.total);
+    }
+
+    #[test]
+    fn flop_matches_brute_force() {
+        assert_matches_brute_force(&["AhAd", "KsQs"], "2c 7c 9c", true);
+        assert_matches_brute_force(&["KhKd", "KcKs", "7h7d"], "2h 3d 4c", false);
+    }
+
+    #[test]
+    fn turn_matches_brute_force() {
+        assert_matches_brute_force(&["9h8h", "AcAd"], "2c 2d Ts 3s", true);
+        assert_matches_brute_force(&["AhKh", "AdKd"], "2c 2s Tc Ts", true);
+        assert_matches_brute_force(&["Js Ts", "Ah2c", "6d6h"], "9s 8d 2h Qc", false);
+    }
+
+    #[test]
+    fn preflop_matches_brute_force() {
+        assert_matches_brute_force(&["7s6s", "AhAd"], "", true);
+    }
+
+    #[test]
+    fn orbit_sizes_add_up_to_every_board() {
+        // The weights of the canonical 3-card boards count each board exactly once
+        let players = holes(&["Ah Kh", "Qs Qd"]);
+        let deck = CardSet::from_cards(&cards("Ah Kh Qs Qd")).complement().to_vec();
+        let symmetry = SuitSymmetry::new(&players, &[], &deck);
+        assert!(!symmetry.is_trivial());
+
+        let mut total = 0;
+        for i in 0..deck.len() {
+            for j in i + 1..deck.len() {
+                for k in j + 1..deck.len() {
+                    total += symmetry.orbit_size(CardSet::from_cards(&[deck[i], deck[j], deck[k]]));
+                }
+            }
+        }
+        assert_eq!(total, 48 * 47 * 46 / 6);
+    }
+}
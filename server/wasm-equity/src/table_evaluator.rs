@@ -0,0 +1,350 @@
+// Lookup-table 7-card evaluator
+//
+// Hands are scored by table lookups instead of counting and scanning ranks:
+// - flushes: a table indexed by the 13-bit rank mask of the flush suit (at most one
+//   suit can hold 5+ of 7 cards, and a flush then beats anything else the 7 cards make)
+// - everything else: a table indexed by a perfect hash of the rank multiset, the
+//   colex rank of the sorted ranks as a combination with repetition
+//
+// The non-flush table is stored as one row per 5-card board rank multiset and one
+// column per hole rank pair, so once a board is prepared (`TableBoard`) each player
+// costs a flush check and a single lookup.
+//
+// Both tables hold a u16 strength from 1 (9-8-7-5-4 high, the weakest 7 cards can
+// make) to 4824 (royal flush), one per distinct 7-card hand value, whose order
+// is exactly the order of `evaluate_7_card_hand`'s HandRank. They are built once from
+// that evaluator on first use.
+
+use std::sync::OnceLock;
+
+use crate::{evaluate_7_card_hand, Card, CardMasks, HandRank};
+
+// Number of multisets of 7 ranks out of 13: C(13 + 7 - 1, 7)
+const RANK_MULTISETS: usize = 50_388;
+
+// Number of multisets of 5 ranks out of 13: C(13 + 5 - 1, 5)
+const BOARD_MULTISETS: usize = 6_188;
+
+// Number of hole rank pairs (pocket pairs included): 13 * 14 / 2
+const HOLE_PAIRS: usize = 91;
+
+struct LookupTables {
+    flush: Vec<u16>,       // by rank mask of the flush suit (bit = rank - 2)
+    board_pairs: Vec<u16>, // by board multiset hash * HOLE_PAIRS + hole pair index
+    // offsets[rank][cards of lower rank][count]: hash contribution of one rank
+    offsets: [[[u32; 5]; 8]; 13],
+}
+
+static TABLES: OnceLock<LookupTables> = OnceLock::new();
+
+fn binomial(n: u32, k: u32) -> u32 {
+    if k > n {
+        return 0;
+    }
+    (0..k).fold(1, |acc, i| acc * (n - i) / (i + 1))
+}
+
+// Colex rank of the sorted ranks s_0 <= .. <= s_6 is sum C(s_i + i, i + 1); the
+// occurrences of one rank contribute a consecutive run of those terms
+fn multiset_offsets() -> [[[u32; 5]; 8]; 13] {
+    let mut offsets = [[[0u32; 5]; 8]; 13];
+    for (rank, by_lower) in offsets.iter_mut().enumerate() {
+        for (lower, by_count) in by_lower.iter_mut().enumerate() {
+            for count in 1..5 {
+                let pos = (lower + count - 1) as u32;
+                by_count[count] = by_count[count - 1] + binomial(rank as u32 + pos, pos + 1);
+            }
+        }
+    }
+    offsets
+}
+
+#[inline(always)]
+fn multiset_hash(offsets: &[[[u32; 5]; 8]; 13], counts: &[u8]) -> usize {
+    let mut hash = 0;
+    let mut lower = 0;
+    for (rank, &count) in counts.iter().enumerate() {
+        hash += offsets[rank][lower][count as usize];
+        lower += count as usize;
+    }
+    hash as usize
+}
+
+// Build 7 cards from per-rank counts without 5 cards of one suit
+fn unsuited_cards(counts: &[u8; 13]) -> [Card; 7] {
+    let mut cards = [Card { rank: 0, suit: 0 }; 7];
+    let mut idx = 0;
+    for (rank, &count) in counts.iter().enumerate() {
+        for _ in 0..count {
+            // Consecutive cards cycle through the suits: no duplicates, at most 2 per suit
+            cards[idx] = Card { rank: rank as u8 + 2, suit: (idx % 4) as u8 };
+            idx += 1;
+        }
+    }
+    cards
+}
+
+// Build 7 cards whose flush suit holds exactly the ranks in `mask` (5-7 bits);
+// fillers pair the top rank, which can make trips at most and never beats the flush
+fn flush_cards(mask: u16) -> [Card; 7] {
+    let mut cards = [Card { rank: 0, suit: 0 }; 7];
+    let mut idx = 0;
+    for rank in 0..13 {
+        if mask & (1 << rank) != 0 {
+            cards[idx] = Card { rank: rank as u8 + 2, suit: 0 };
+            idx += 1;
+        }
+    }
+    let top = cards[idx - 1].rank;
+    for suit in 1..=(7 - idx) as u8 {
+        cards[idx] = Card { rank: top, suit };
+        idx += 1;
+    }
+    cards
+}
+
+fn evaluate_cards(cards: &[Card; 7]) -> HandRank {
+    evaluate_7_card_hand(&[cards[0], cards[1]], &[cards[2], cards[3], cards[4], cards[5], cards[6]])
+}
+
+// Every multiset of `size` ranks with at most 4 of a rank, as per-rank counts
+fn rank_multisets(size: u8) -> Vec<[u8; 13]> {
+    fn fill(rank: usize, left: u8, counts: &mut [u8; 13], out: &mut Vec<[u8; 13]>) {
+        if rank == 13 {
+            if left == 0 {
+                out.push(*counts);
+            }
+            return;
+        }
+        for count in 0..=left.min(4) {
+            counts[rank] = count;
+            fill(rank + 1, left - count, counts, out);
+        }
+        counts[rank] = 0;
+    }
+
+    let mut out = Vec::new();
+    fill(0, size, &mut [0u8; 13], &mut out);
+    out
+}
+
+// Colex index of a hole rank pair (0-based ranks, any order)
+#[inline(always)]
+fn pair_index(a: usize, b: usize) -> usize {
+    let (low, high) = if a <= b { (a, b) } else { (b, a) };
+    low + high * (high + 1) / 2
+}
+
+/// Column of a hole card pair in the (board multiset, hole rank pair) table; it does
+/// not depend on the board, so callers evaluating many boards can compute it once
+#[inline(always)]
+pub fn hole_pair_index(hole: &[Card; 2]) -> usize {
+    pair_index((hole[0].rank - 2) as usize, (hole[1].rank - 2) as usize)
+}
+
+fn build_tables() -> LookupTables {
+    let offsets = multiset_offsets();
+    let multisets = rank_multisets(7);
+    let flush_masks: Vec<u16> = (0u16..1 << 13)
+        .filter(|mask| (5..=7).contains(&mask.count_ones()))
+        .collect();
+
+    let multiset_ranks: Vec<HandRank> =
+        multisets.iter().map(|counts| evaluate_cards(&unsuited_cards(counts))).collect();
+    let flush_ranks: Vec<HandRank> =
+        flush_masks.iter().map(|&mask| evaluate_cards(&flush_cards(mask))).collect();
+
+    // Strength = position among the distinct HandRank values (1-based)
+    let mut distinct: Vec<HandRank> = multiset_ranks.iter().chain(flush_ranks.iter()).copied().collect();
+    distinct.sort_unstable();
+    distinct.dedup();
+    let strength = |rank: HandRank| (distinct.binary_search(&rank).unwrap() + 1) as u16;
+
+    let mut rank_set = vec![0u16; RANK_MULTISETS];
+    for (counts, &rank) in multisets.iter().zip(multiset_ranks.iter()) {
+        rank_set[multiset_hash(&offsets, counts)] = strength(rank);
+    }
+    let mut flush = vec![0u16; 1 << 13];
+    for (&mask, &rank) in flush_masks.iter().zip(flush_ranks.iter()) {
+        flush[mask as usize] = strength(rank);
+    }
+
+    // Split the 7-card table into (5-card board multiset, hole rank pair) rows
+    let mut board_pairs = vec![0u16; BOARD_MULTISETS * HOLE_PAIRS];
+    for board in rank_multisets(5) {
+        let row = multiset_hash(&offsets, &board) * HOLE_PAIRS;
+        for high in 0..13 {
+            for low in 0..=high {
+                let mut counts = board;
+                counts[high] += 1;
+                counts[low] += 1;
+                if counts.iter().all(|&count| count <= 4) {
+                    board_pairs[row + pair_index(low, high)] = rank_set[multiset_hash(&offsets, &counts)];
+                }
+            }
+        }
+    }
+
+    LookupTables { flush, board_pairs, offsets }
+}
+
+/// Build the lookup tables now instead of on the first evaluation
+pub fn init_tables() {
+    TABLES.get_or_init(build_tables);
+}
+
+/// Board-dependent part of a table evaluation, computed once per complete board
+pub struct TableBoard {
+    tables: &'static LookupTables,
+    row: usize,                // board multiset row in `board_pairs`
+    flush_suit: Option<usize>, // the only suit with 3+ board cards, if any
+    flush_bits: u16,           // board ranks of that suit
+}
+
+impl TableBoard {
+    /// Prepare the masks of a complete 5-card board
+    #[inline(always)]
+    pub fn new(board: &CardMasks) -> Self {
+        let tables = TABLES.get_or_init(build_tables);
+        let flush_suit = board.suit_bits.iter().position(|bits| bits.count_ones() >= 3);
+        TableBoard {
+            tables,
+            row: multiset_hash(&tables.offsets, &board.rank_counts[2..]) * HOLE_PAIRS,
+            flush_suit,
+            flush_bits: flush_suit.map_or(0, |suit| board.suit_bits[suit]),
+        }
+    }
+
+    /// Strength of the best 5-card hand from this board plus `hole` (1..=4824)
+    ///
+    /// Orders hands exactly like `evaluate_7_card_hand`.
+    #[inline(always)]
+    pub fn evaluate(&self, hole: &[Card; 2]) -> u16 {
+        if let Some((suit, mut bits)) = self.flush() {
+            for card in hole {
+                if card.suit as usize == suit {
+                    bits |= 1 << card.rank;
+                }
+            }
+            if bits.count_ones() >= 5 {
+                return self.flush_strength(bits);
+            }
+        }
+        self.pair_strength(hole_pair_index(hole))
+    }
+
+    /// The only suit with 3+ board cards (the only possible flush suit) and the board
+    /// ranks of that suit, if any
+    #[inline(always)]
+    pub fn flush(&self) -> Option<(usize, u16)> {
+        self.flush_suit.map(|suit| (suit, self.flush_bits))
+    }
+
+    /// Strength of a flush made of the ranks in `bits` (bit = rank, 5-7 bits)
+    #[inline(always)]
+    pub fn flush_strength(&self, bits: u16) -> u16 {
+        self.tables.flush[(bits >> 2) as usize]
+    }
+
+    /// Strength without a flush for the hole pair at `pair` (see `hole_pair_index`)
+    #[inline(always)]
+    pub fn pair_strength(&self, pair: usize) -> u16 {
+        self.tables.board_pairs[self.row + pair]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card_set::CardSet;
+    use crate::{first_card_indices, parse_card_list, BoardEnumerator, EvaluatorBackend, Rng};
+
+    // Table strength of 7 cards: the last five as the board, the first two as the hole
+    fn table_strength(cards: &[Card; 7]) -> u16 {
+        let board = CardMasks::from_cards(&cards[2..]);
+        TableBoard::new(&board).evaluate(&[cards[0], cards[1]])
+    }
+
+    // Strengths must order hands exactly like HandRank: equal iff equal, lower iff lower
+    fn assert_same_order(mut hands: Vec<(HandRank, u16)>) {
+        hands.sort_unstable();
+        for pair in hands.windows(2) {
+            let ((rank_a, strength_a), (rank_b, strength_b)) = (pair[0], pair[1]);
+            assert_eq!(rank_a == rank_b, strength_a == strength_b, "{:x} vs {:x}", rank_a, rank_b);
+            assert!(strength_a <= strength_b, "{:x} vs {:x}", rank_a, rank_b);
+        }
+    }
+
+    #[test]
+    fn every_rank_multiset_and_flush_orders_like_hand_rank() {
+        let mut hands = Vec::new();
+        for counts in rank_multisets(7) {
+            let mut cards = unsuited_cards(&counts);
+            hands.push((evaluate_cards(&cards), table_strength(&cards)));
+            // Same cards with a different hole pair: another board row and column
+            cards.rotate_left(3);
+            hands.push((evaluate_cards(&cards), table_strength(&cards)));
+        }
+        for mask in (0u16..1 << 13).filter(|mask| (5..=7).contains(&mask.count_ones())) {
+            let mut cards = flush_cards(mask);
+            hands.push((evaluate_cards(&cards), table_strength(&cards)));
+            cards.reverse();
+            hands.push((evaluate_cards(&cards), table_strength(&cards)));
+        }
+        assert_same_order(hands);
+    }
+
+    #[test]
+    fn random_hands_order_like_hand_rank() {
+        let deck = CardSet::FULL_DECK.to_vec();
+        let mut rng = Rng::new(7);
+        let hands = (0..300_000)
+            .map(|_| {
+                let mut cards = [Card { rank: 0, suit: 0 }; 7];
+                let mut used = CardSet::EMPTY;
+                for card in cards.iter_mut() {
+                    *card = loop {
+                        let pick = deck[rng.below(52) as usize];
+                        if used.insert(pick) {
+                            break pick;
+                        }
+                    };
+                }
+                (evaluate_cards(&cards), table_strength(&cards))
+            })
+            .collect();
+        assert_same_order(hands);
+    }
+
+    #[test]
+    fn backends_give_identical_counts() {
+        let spots: [(&[&str], &str); 4] = [
+            (&["AhKh", "QsQd"], "Jh 7h 2c"),
+            (&["9s8s", "AcAd", "KhKc"], "7s 6d 2s Th"),
+            (&["AhAd", "KsKc", "QhJh", "Tc9c"], "2h 2c 9h"),
+            (&["5c4c", "AsKd"], "3c 2c Ac"),
+        ];
+        for (holes, board) in spots {
+            let players: Vec<[Card; 2]> = holes
+                .iter()
+                .map(|text| parse_card_list(text).unwrap().try_into().unwrap())
+                .collect();
+            let board = parse_card_list(board).unwrap();
+            let known: Vec<Card> = players.iter().flatten().chain(board.iter()).copied().collect();
+            let deck = CardSet::from_cards(&known).complement().to_vec();
+
+            let counts = |backend| {
+                let first = first_card_indices(deck.len(), 5 - board.len());
+                let mut enumerator = BoardEnumerator::new(&players, &board, &deck, first);
+                enumerator.showdown.backend = backend;
+                enumerator.step(u64::MAX);
+                enumerator.counts
+            };
+            let direct = counts(EvaluatorBackend::Direct);
+            let table = counts(EvaluatorBackend::Table);
+            assert_eq!(table.wins, direct.wins);
+            assert_eq!(table.ties, direct.ties);
+            assert_eq!(table.total, direct.total);
+        }
+    }
+}
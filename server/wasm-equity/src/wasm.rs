@@ -0,0 +1,774 @@
+// wasm-bindgen exports used by the Node server (the `wasm` feature)
+//
+// Each export takes flattened rank/suit arrays (ranks 2-14, suits 0=c, 1=d, 2=h, 3=s),
+// checks their lengths, calls the core function of the crate and returns its result
+// as a plain JS object; an `EquityError` becomes a thrown JS `Error`.
+
+use wasm_bindgen::prelude::*;
+
+use crate::results::{to_js, EquityJobResult, EquityJobStatus};
+use crate::{
+    adaptive_equity, board_count, check_board_count, check_player_count, enumerate_equity, equity_result,
+    equity_vs_random, exact_equity, first_card_indices, lookup_preflop_table, monte_carlo_equity,
+    prepare_equity_inputs, register_catalog, turn_outs, AdaptiveLimits, BoardEnumerator, Card, CardSet, EquityCounts,
+    EquityError, EquityInputs, HandValue, MessageCatalog,
+};
+
+// Parse flattened rank/suit arrays into cards
+fn parse_cards(ranks: &[u8], suits: &[u8]) -> Vec<Card> {
+    ranks
+        .iter()
+        .zip(suits.iter())
+        .map(|(&rank, &suit)| Card { rank, suit })
+        .collect()
+}
+
+// Parse flattened player arrays (2 cards per player) into fixed-size holes
+fn parse_players(player_ranks: &[u8], player_suits: &[u8], num_players: usize) -> Vec<[Card; 2]> {
+    let mut players: Vec<[Card; 2]> = Vec::with_capacity(num_players);
+    for i in 0..num_players {
+        let card1_idx = i * 2;
+        let card2_idx = i * 2 + 1;
+        players.push([
+            Card {
+                rank: player_ranks[card1_idx],
+                suit: player_suits[card1_idx],
+            },
+            Card {
+                rank: player_ranks[card2_idx],
+                suit: player_suits[card2_idx],
+            },
+        ]);
+    }
+    players
+}
+
+// Validate the player count and that the flattened player arrays hold 2 cards each
+fn check_player_lengths(player_ranks: &[u8], player_suits: &[u8], num_players: usize) -> Result<(), EquityError> {
+    check_player_count(num_players)?;
+    if player_ranks.len() != num_players * 2 || player_suits.len() != num_players * 2 {
+        return Err(EquityError::BadLength {
+            cards: "Each player",
+            expected: "exactly 2 cards",
+        });
+    }
+    Ok(())
+}
+
+// Validate the lengths of flattened board (0-5 cards) and dead card arrays
+fn check_board_and_dead_lengths(
+    board_ranks: &[u8],
+    board_suits: &[u8],
+    dead_ranks: &[u8],
+    dead_suits: &[u8],
+) -> Result<(), EquityError> {
+    if board_ranks.len() != board_suits.len() {
+        return Err(EquityError::BadLength {
+            cards: "Board cards",
+            expected: "as many suits as ranks",
+        });
+    }
+    check_board_count(board_ranks.len())?;
+    if dead_ranks.len() != dead_suits.len() {
+        return Err(EquityError::BadLength {
+            cards: "Dead cards",
+            expected: "as many suits as ranks",
+        });
+    }
+    Ok(())
+}
+
+// Validate flattened player/board/dead arrays and build the remaining deck
+fn parse_equity_inputs(
+    player_ranks: &[u8],
+    player_suits: &[u8],
+    board_ranks: &[u8],
+    board_suits: &[u8],
+    dead_ranks: &[u8],
+    dead_suits: &[u8],
+    num_players: usize,
+) -> Result<EquityInputs, EquityError> {
+    check_player_lengths(player_ranks, player_suits, num_players)?;
+    check_board_and_dead_lengths(board_ranks, board_suits, dead_ranks, dead_suits)?;
+
+    prepare_equity_inputs(
+        parse_players(player_ranks, player_suits, num_players),
+        parse_cards(board_ranks, board_suits),
+        &parse_cards(dead_ranks, dead_suits),
+    )
+}
+
+// Validate flattened player arrays and a caller-supplied deck (preflop, no board)
+//
+// The deck may repeat cards or include the players' cards; both are dropped.
+fn parse_preflop_inputs(
+    player_ranks: &[u8],
+    player_suits: &[u8],
+    deck_ranks: &[u8],
+    deck_suits: &[u8],
+    num_players: usize,
+) -> Result<EquityInputs, EquityError> {
+    check_player_lengths(player_ranks, player_suits, num_players)?;
+    if deck_ranks.len() != deck_suits.len() {
+        return Err(EquityError::BadLength {
+            cards: "Deck cards",
+            expected: "as many suits as ranks",
+        });
+    }
+
+    let players = parse_players(player_ranks, player_suits, num_players);
+    let player_cards: Vec<Card> = players.iter().flatten().copied().collect();
+    let player_cards = CardSet::try_from_cards(&player_cards)?;
+    let deck = parse_cards(deck_ranks, deck_suits)
+        .into_iter()
+        .map(CardSet::validate)
+        .collect::<Result<CardSet, _>>()?;
+
+    let remaining_deck = (deck - player_cards).to_vec();
+    if remaining_deck.len() < 5 {
+        return Err(EquityError::InsufficientDeck {
+            needed: 5,
+            available: remaining_deck.len(),
+        });
+    }
+
+    Ok(EquityInputs {
+        players,
+        known_board: Vec::new(),
+        remaining_deck,
+    })
+}
+
+/// Calculate preflop equity using exact enumeration
+/// 
+/// Input format (all arrays flattened):
+/// - player_ranks: array of ranks for all player cards (2 cards per player)
+/// - player_suits: array of suits for all player cards (0=c, 1=d, 2=h, 3=s)
+/// - deck_ranks: array of ranks for remaining deck cards
+/// - deck_suits: array of suits for remaining deck cards
+/// - num_players: number of players
+/// 
+/// Returns an `EquityResult` object: {win:[0.5,0.5],tie:[0,0],lose:[0.5,0.5],samples:1712304};
+/// throws for mismatched lengths, out-of-range or duplicate hole cards, or a deck
+/// without 5 cards left to deal.
+#[wasm_bindgen(unchecked_return_type = "EquityResult")]
+pub fn calculate_preflop_equity(
+    player_ranks: &[u8],
+    player_suits: &[u8],
+    deck_ranks: &[u8],
+    deck_suits: &[u8],
+    num_players: usize,
+) -> Result<JsValue, JsError> {
+    let inputs = parse_preflop_inputs(player_ranks, player_suits, deck_ranks, deck_suits, num_players)?;
+    let counts = enumerate_equity(&inputs.players, &inputs.known_board, &inputs.remaining_deck);
+    to_js(&equity_result(&counts))
+}
+
+/// Calculate equity on any street using exact enumeration
+/// 
+/// Input format (all arrays flattened):
+/// - player_ranks: array of ranks for all player cards (2 cards per player)
+/// - player_suits: array of suits for all player cards (0=c, 1=d, 2=h, 3=s)
+/// - board_ranks: ranks of the known board cards (0, 3, 4 or 5 cards)
+/// - board_suits: suits of the known board cards
+/// - dead_ranks: ranks of dead/mucked cards that cannot be dealt
+/// - dead_suits: suits of dead/mucked cards
+/// - num_players: number of players
+/// 
+/// The remaining deck is built here from the 52-card deck minus every known
+/// card; every completion of the missing `5 - board.len()` cards is enumerated
+/// (a complete board is a single showdown).
+/// 
+/// Returns an `EquityResult` object: {win:[0.5,0.5],tie:[0,0],lose:[0.5,0.5],samples:990};
+/// throws for malformed, out-of-range or duplicate cards, more than 23 players, or a
+/// deck too short to complete the board.
+#[wasm_bindgen(unchecked_return_type = "EquityResult")]
+pub fn calculate_equity(
+    player_ranks: &[u8],
+    player_suits: &[u8],
+    board_ranks: &[u8],
+    board_suits: &[u8],
+    dead_ranks: &[u8],
+    dead_suits: &[u8],
+    num_players: usize,
+) -> Result<JsValue, JsError> {
+    check_player_lengths(player_ranks, player_suits, num_players)?;
+    check_board_and_dead_lengths(board_ranks, board_suits, dead_ranks, dead_suits)?;
+
+    let result = exact_equity(
+        &parse_players(player_ranks, player_suits, num_players),
+        &parse_cards(board_ranks, board_suits),
+        &parse_cards(dead_ranks, dead_suits),
+    )?;
+    to_js(&result)
+}
+
+// Where an equity job stands: still enumerating or finished
+enum JobState {
+    Enumerating(Box<BoardEnumerator>),
+    Finished(EquityCounts),
+}
+
+/// Exact equity calculation run in steps, so a caller can report progress, time-slice
+/// the enumeration (e.g. between Node event loop turns or in a Web Worker) and abort it
+///
+/// Takes the same inputs as `calculate_equity`. Each `step(max_boards)` visits at most
+/// that many boards; once every board is visited `result()` equals `calculate_equity`.
+#[wasm_bindgen]
+pub struct EquityJob {
+    state: JobState,
+    total_boards: u64,
+    cancelled: bool,
+}
+
+#[wasm_bindgen]
+impl EquityJob {
+    /// Validate the cards and prepare the enumeration; throws for invalid input
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        player_ranks: &[u8],
+        player_suits: &[u8],
+        board_ranks: &[u8],
+        board_suits: &[u8],
+        dead_ranks: &[u8],
+        dead_suits: &[u8],
+        num_players: usize,
+    ) -> Result<EquityJob, JsError> {
+        let inputs = parse_equity_inputs(
+            player_ranks, player_suits, board_ranks, board_suits, dead_ranks, dead_suits, num_players,
+        )?;
+
+        let missing = 5 - inputs.known_board.len();
+        let total_boards = board_count(inputs.remaining_deck.len(), missing);
+
+        // Heads-up preflop without dead cards finishes at once when a table is loaded
+        if inputs.players.len() == 2 && inputs.known_board.is_empty() && dead_ranks.is_empty() {
+            if let Some(counts) = lookup_preflop_table(inputs.players[0], inputs.players[1]) {
+                return Ok(EquityJob {
+                    state: JobState::Finished(counts),
+                    total_boards,
+                    cancelled: false,
+                });
+            }
+        }
+
+        let enumerator = BoardEnumerator::new(
+            &inputs.players,
+            &inputs.known_board,
+            &inputs.remaining_deck,
+            first_card_indices(inputs.remaining_deck.len(), missing),
+        );
+        Ok(EquityJob {
+            state: JobState::Enumerating(Box::new(enumerator)),
+            total_boards,
+            cancelled: false,
+        })
+    }
+
+    /// Visit up to `max_boards` more boards (nothing once finished or cancelled)
+    ///
+    /// Returns an `EquityJobStatus` object:
+    /// {boards:428076,total_boards:1712304,progress:0.25,done:false,cancelled:false}
+    #[wasm_bindgen(unchecked_return_type = "EquityJobStatus")]
+    pub fn step(&mut self, max_boards: u32) -> Result<JsValue, JsError> {
+        if !self.cancelled {
+            if let JobState::Enumerating(enumerator) = &mut self.state {
+                if enumerator.step(max_boards as u64) {
+                    let counts = std::mem::replace(&mut enumerator.counts, EquityCounts::new(0));
+                    self.state = JobState::Finished(counts);
+                }
+            }
+        }
+        to_js(&EquityJobStatus {
+            boards: self.boards(),
+            total_boards: self.total_boards,
+            progress: self.progress(),
+            done: self.is_done(),
+            cancelled: self.cancelled,
+        })
+    }
+
+    /// Stop the job: later `step` calls do nothing, `result()` keeps the partial counts
+    pub fn cancel(&mut self) {
+        self.cancelled = true;
+    }
+
+    /// Fraction of the boards visited so far (0-1)
+    pub fn progress(&self) -> f64 {
+        if self.total_boards == 0 {
+            return 0.0;
+        }
+        self.boards() as f64 / self.total_boards as f64
+    }
+
+    /// True once every board is visited or the job is cancelled
+    pub fn is_finished(&self) -> bool {
+        self.cancelled || self.is_done()
+    }
+
+    /// Equity over the boards visited so far, in the `calculate_equity` shape plus the
+    /// job status
+    ///
+    /// Until the job is done this covers a prefix of the boards in deck order, not a
+    /// random sample, so partial results are only indicative.
+    ///
+    /// Returns an `EquityJobResult` object:
+    /// {win:[...],tie:[...],lose:[...],samples:428076,progress:0.25,done:false,cancelled:false}
+    #[wasm_bindgen(unchecked_return_type = "EquityJobResult")]
+    pub fn result(&self) -> Result<JsValue, JsError> {
+        let counts = match &self.state {
+            JobState::Enumerating(enumerator) => &enumerator.counts,
+            JobState::Finished(counts) => counts,
+        };
+        to_js(&EquityJobResult {
+            equity: equity_result(counts),
+            progress: self.progress(),
+            done: self.is_done(),
+            cancelled: self.cancelled,
+        })
+    }
+}
+
+impl EquityJob {
+    // Boards visited so far
+    fn boards(&self) -> u64 {
+        match &self.state {
+            JobState::Enumerating(enumerator) => enumerator.visited,
+            JobState::Finished(_) => self.total_boards,
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        matches!(self.state, JobState::Finished(_))
+    }
+}
+
+/// Calculate equity on any street by Monte Carlo sampling
+/// 
+/// Takes the same card inputs as `calculate_equity`, plus:
+/// - samples: number of random board completions to evaluate
+/// - seed: seed for the deterministic generator (same seed => same result)
+/// 
+/// Intended for multiway pots where exact enumeration is too slow for the request path.
+/// 
+/// Returns an `EquityResult` object with, per player, the standard error of the
+/// equity (win + tie share) and its 95% confidence interval:
+/// {win:[...],tie:[...],lose:[...],samples:100000,std_error:[0.0013,0.0013],ci95:[[0.80,0.81],[0.18,0.19]]};
+/// throws for invalid input.
+#[wasm_bindgen(unchecked_return_type = "EquityResult")]
+#[allow(clippy::too_many_arguments)]
+pub fn calculate_equity_monte_carlo(
+    player_ranks: &[u8],
+    player_suits: &[u8],
+    board_ranks: &[u8],
+    board_suits: &[u8],
+    dead_ranks: &[u8],
+    dead_suits: &[u8],
+    num_players: usize,
+    samples: u32,
+    seed: u32,
+) -> Result<JsValue, JsError> {
+    check_player_lengths(player_ranks, player_suits, num_players)?;
+    check_board_and_dead_lengths(board_ranks, board_suits, dead_ranks, dead_suits)?;
+
+    let result = monte_carlo_equity(
+        &parse_players(player_ranks, player_suits, num_players),
+        &parse_cards(board_ranks, board_suits),
+        &parse_cards(dead_ranks, dead_suits),
+        samples as u64,
+        seed as u64,
+    )?;
+    to_js(&result)
+}
+
+/// Calculate equity by sampling until a precision target or time budget is reached
+/// 
+/// Takes the same card inputs as `calculate_equity`, plus:
+/// - target_precision: stop once every player's 95% confidence half-width is at most
+///   this (e.g. 0.001 for +/-0.1% equity); 0 disables the precision target
+/// - time_budget_ms: stop once this many milliseconds have elapsed; 0 disables it
+/// - max_samples: hard cap on sampled boards; 0 means no cap
+/// - seed: seed for the deterministic generator
+/// 
+/// Returns the same `EquityResult` as `calculate_equity_monte_carlo`, plus the limit
+/// that stopped sampling: stop_reason: "precision" | "time" | "samples";
+/// throws for invalid input.
+#[wasm_bindgen(unchecked_return_type = "EquityResult")]
+#[allow(clippy::too_many_arguments)]
+pub fn calculate_equity_adaptive(
+    player_ranks: &[u8],
+    player_suits: &[u8],
+    board_ranks: &[u8],
+    board_suits: &[u8],
+    dead_ranks: &[u8],
+    dead_suits: &[u8],
+    num_players: usize,
+    target_precision: f64,
+    time_budget_ms: f64,
+    max_samples: u32,
+    seed: u32,
+) -> Result<JsValue, JsError> {
+    check_player_lengths(player_ranks, player_suits, num_players)?;
+    check_board_and_dead_lengths(board_ranks, board_suits, dead_ranks, dead_suits)?;
+
+    let limits = AdaptiveLimits {
+        target_precision,
+        time_budget_ms,
+        max_samples: max_samples as u64,
+    };
+    let result = adaptive_equity(
+        &parse_players(player_ranks, player_suits, num_players),
+        &parse_cards(board_ranks, board_suits),
+        &parse_cards(dead_ranks, dead_suits),
+        &limits,
+        seed as u64,
+    )?;
+    to_js(&result)
+}
+
+/// Compute turn outs for heads-up Texas Hold'em
+/// 
+/// Input format:
+/// - hero_ranks: 2 ranks for hero's hole cards
+/// - hero_suits: 2 suits for hero's hole cards
+/// - villain_ranks: 2 ranks for villain's hole cards
+/// - villain_suits: 2 suits for villain's hole cards
+/// - board_ranks: 4 ranks for turn board
+/// - board_suits: 4 suits for turn board
+/// 
+/// Returns an `OutsResult` object with the outs or the reason they are suppressed:
+/// {
+///   suppressed: null | { reason: "string", baseline_win: 0.45, baseline_tie: 0.0 },
+///   win_outs: [{rank: 14, suit: 0, category: 5}],
+///   tie_outs: [{rank: 13, suit: 1, category: 2}],
+///   baseline_win: 0.15,
+///   baseline_tie: 0.05,
+///   baseline_lose: 0.80,
+///   total_river_cards: 44
+/// }
+/// Throws for invalid input.
+#[wasm_bindgen(unchecked_return_type = "OutsResult")]
+pub fn compute_turn_outs(
+    hero_ranks: &[u8],
+    hero_suits: &[u8],
+    villain_ranks: &[u8],
+    villain_suits: &[u8],
+    board_ranks: &[u8],
+    board_suits: &[u8],
+) -> Result<JsValue, JsError> {
+    // Validate inputs
+    if hero_ranks.len() != 2 || hero_suits.len() != 2 {
+        return Err(EquityError::BadLength {
+            cards: "Hero",
+            expected: "exactly 2 cards",
+        }
+        .into());
+    }
+    if villain_ranks.len() != 2 || villain_suits.len() != 2 {
+        return Err(EquityError::BadLength {
+            cards: "Villain",
+            expected: "exactly 2 cards",
+        }
+        .into());
+    }
+    if board_ranks.len() != 4 || board_suits.len() != 4 {
+        return Err(EquityError::BadLength {
+            cards: "Board",
+            expected: "exactly 4 cards (turn)",
+        }
+        .into());
+    }
+    
+    // Parse hero and villain hole cards
+    let hero_hole = [
+        Card { rank: hero_ranks[0], suit: hero_suits[0] },
+        Card { rank: hero_ranks[1], suit: hero_suits[1] },
+    ];
+    
+    let villain_hole = [
+        Card { rank: villain_ranks[0], suit: villain_suits[0] },
+        Card { rank: villain_ranks[1], suit: villain_suits[1] },
+    ];
+    
+    // Parse board (4 cards)
+    let board_4 = [
+        Card { rank: board_ranks[0], suit: board_suits[0] },
+        Card { rank: board_ranks[1], suit: board_suits[1] },
+        Card { rank: board_ranks[2], suit: board_suits[2] },
+        Card { rank: board_ranks[3], suit: board_suits[3] },
+    ];
+    
+    to_js(&turn_outs(hero_hole, villain_hole, board_4)?)
+}
+
+/// Evaluate the best five-card hand among 5, 6 or 7 cards
+/// 
+/// Input format:
+/// - ranks: ranks of the cards (2-14)
+/// - suits: suits of the cards (0=c, 1=d, 2=h, 3=s)
+/// 
+/// Returns a `HandValue` object in the shape of `HandRank` (handInterfaces.ts):
+/// {category:6,tiebreak:[14,13]} for aces full of kings. Categories run 0 (high card)
+/// to 9 (royal flush, empty tiebreak); straights carry only their high card (5 for the
+/// wheel). Throws for fewer than 5 or more than 7 cards, or invalid or duplicate cards.
+#[wasm_bindgen(unchecked_return_type = "HandValue")]
+pub fn evaluate_hand(ranks: &[u8], suits: &[u8]) -> Result<JsValue, JsError> {
+    if ranks.len() != suits.len() {
+        return Err(EquityError::BadLength {
+            cards: "A hand",
+            expected: "as many suits as ranks",
+        }
+        .into());
+    }
+    to_js(&crate::evaluate_hand(&parse_cards(ranks, suits))?)
+}
+
+/// Evaluate 5-7 cards like `evaluate_hand` and find the five that make the hand
+/// 
+/// Input format:
+/// - ranks: ranks of the cards (2-14)
+/// - suits: suits of the cards (0=c, 1=d, 2=h, 3=s)
+/// 
+/// Returns a `HandValue` object with `best_five`, the indices of those cards into the
+/// input in tiebreak order (made hand, then kickers; the wheel's ace last):
+/// {category:1,tiebreak:[14,13,9,7],best_five:[0,4,1,6,3]}; throws like `evaluate_hand`.
+#[wasm_bindgen(unchecked_return_type = "HandValue")]
+pub fn best_hand(ranks: &[u8], suits: &[u8]) -> Result<JsValue, JsError> {
+    if ranks.len() != suits.len() {
+        return Err(EquityError::BadLength {
+            cards: "A hand",
+            expected: "as many suits as ranks",
+        }
+        .into());
+    }
+    to_js(&crate::best_hand(&parse_cards(ranks, suits))?)
+}
+
+/// Describe a hand value in words, e.g. "Full house, Aces full of Kings"
+/// 
+/// Input format:
+/// - category: hand category (0 = high card ... 9 = royal flush)
+/// - tiebreak: tiebreak ranks of the category, as returned by `evaluate_hand`
+/// - locale: catalog to use, e.g. "de-AT" (falls back to "de", then English)
+/// 
+/// Returns the description: "Pair of Queens, Ace kicker"; throws if the tiebreak does
+/// not fit the category.
+#[wasm_bindgen]
+pub fn describe_hand(category: u8, tiebreak: &[u8], locale: Option<String>) -> Result<String, JsError> {
+    let value = HandValue {
+        category,
+        tiebreak: tiebreak.to_vec(),
+        best_five: None,
+    };
+    Ok(crate::describe_hand(&value, locale.as_deref().unwrap_or("en"))?)
+}
+
+/// Register the message catalog `describe_hand` uses for a locale
+/// 
+/// Input: a `MessageCatalog` object with `locale`, the 13 rank names and plurals (Two to
+/// Ace), the 10 category templates and the `with_kicker` and `wheel` templates.
+/// 
+/// Replaces any catalog registered for the same locale; throws if the object is not a
+/// valid catalog.
+#[wasm_bindgen]
+pub fn register_hand_catalog(
+    #[wasm_bindgen(unchecked_param_type = "MessageCatalog")] catalog: JsValue,
+) -> Result<(), JsError> {
+    let catalog: MessageCatalog =
+        serde_wasm_bindgen::from_value(catalog).map_err(|error| JsError::new(&error.to_string()))?;
+    Ok(register_catalog(catalog)?)
+}
+
+/// Parse a hand range and print it back in normalized notation
+/// 
+/// Input format:
+/// - range: range string, e.g. "TT+, AKs, A5s-A2s, KQo, 76s:0.5"
+/// - blocked_ranks / blocked_suits: known board and dead cards; combos using them are removed
+/// 
+/// Returns a `NormalizedRange` object:
+/// {range:"TT+, AKs, A5s-A2s, KQo, 76s:0.5",combos:62,weighted_combos:60.0};
+/// throws for an invalid range or card.
+#[wasm_bindgen(unchecked_return_type = "NormalizedRange")]
+pub fn normalize_range(range: &str, blocked_ranks: &[u8], blocked_suits: &[u8]) -> Result<JsValue, JsError> {
+    if blocked_ranks.len() != blocked_suits.len() {
+        return Err(EquityError::BadLength {
+            cards: "Blocked cards",
+            expected: "as many suits as ranks",
+        }
+        .into());
+    }
+    to_js(&crate::normalize_range(range, &parse_cards(blocked_ranks, blocked_suits))?)
+}
+
+/// Calculate equity of a hand or range against one or more opponent ranges
+/// 
+/// Input format:
+/// - hero_range: hero's range or exact hand, e.g. "AhKh" or "TT+, AKs"
+/// - opponent_ranges: opponent ranges separated by ';', e.g. "QQ+, AK; 22+"
+/// - board_ranks / board_suits: the known board cards (0-5 cards)
+/// - dead_ranks / dead_suits: dead or mucked cards
+/// - samples: sampled (tuple, board) draws when exact enumeration is too large
+/// - seed: seed for the deterministic generator
+/// 
+/// Combos that share a card with the board, dead cards or each other are removed,
+/// and every combo counts with its range weight.
+/// 
+/// Returns a `RangeEquityResult` object: the `calculate_equity` shape (player 0 is the
+/// hero), whether the result is exact, and the hero's equity per combo (null if the
+/// combo never met a non-overlapping opponent hand):
+/// {win:[...],tie:[...],lose:[...],samples:1234,exact:true,
+///  hero_combos:[{combo:"AhKh",weight:1.0,equity:0.53}]};
+/// throws for invalid input.
+#[wasm_bindgen(unchecked_return_type = "RangeEquityResult")]
+#[allow(clippy::too_many_arguments)]
+pub fn calculate_range_equity(
+    hero_range: &str,
+    opponent_ranges: &str,
+    board_ranks: &[u8],
+    board_suits: &[u8],
+    dead_ranks: &[u8],
+    dead_suits: &[u8],
+    samples: u32,
+    seed: u32,
+) -> Result<JsValue, JsError> {
+    check_board_and_dead_lengths(board_ranks, board_suits, dead_ranks, dead_suits)?;
+
+    let opponent_ranges: Vec<&str> = opponent_ranges
+        .split(';')
+        .filter(|text| !text.trim().is_empty())
+        .collect();
+    let result = crate::range_vs_range_equity(
+        hero_range,
+        &opponent_ranges,
+        &parse_cards(board_ranks, board_suits),
+        &parse_cards(dead_ranks, dead_suits),
+        samples as u64,
+        seed as u64,
+    )?;
+    to_js(&result)
+}
+
+/// Calculate equity with some seats holding random (unknown) hands
+/// 
+/// Input format (all arrays flattened):
+/// - player_ranks / player_suits: 2 cards per seat; a seat whose ranks are both 0 is
+///   an unknown hand dealt at random from the remaining deck
+/// - board_ranks / board_suits: the known board cards (0-5 cards)
+/// - dead_ranks / dead_suits: dead or mucked cards
+/// - num_players: number of seats (known and random)
+/// - samples: sampled deals when exact enumeration is too large
+/// - seed: seed for the deterministic generator
+/// 
+/// Exact (every random hand x every board) when that is feasible, e.g. one random
+/// opponent on the flop; sampled otherwise.
+/// 
+/// Returns an `EquityResult` object:
+/// {win:[...],tie:[...],lose:[...],samples:1070190,exact:true};
+/// throws for invalid input.
+#[wasm_bindgen(unchecked_return_type = "EquityResult")]
+#[allow(clippy::too_many_arguments)]
+pub fn calculate_equity_vs_random(
+    player_ranks: &[u8],
+    player_suits: &[u8],
+    board_ranks: &[u8],
+    board_suits: &[u8],
+    dead_ranks: &[u8],
+    dead_suits: &[u8],
+    num_players: usize,
+    samples: u32,
+    seed: u32,
+) -> Result<JsValue, JsError> {
+    check_player_lengths(player_ranks, player_suits, num_players)?;
+    check_board_and_dead_lengths(board_ranks, board_suits, dead_ranks, dead_suits)?;
+
+    // Split seats into known hands and random seats (both ranks 0)
+    let seats = parse_players(player_ranks, player_suits, num_players);
+    let mut known_holes: Vec<Option<[Card; 2]>> = Vec::with_capacity(num_players);
+    for (seat, hole) in seats.iter().enumerate() {
+        match (hole[0].rank, hole[1].rank) {
+            (0, 0) => known_holes.push(None),
+            (0, _) | (_, 0) => {
+                return Err(EquityError::InvalidArgument(format!(
+                    "Seat {} must have 2 known cards or be marked random",
+                    seat + 1
+                ))
+                .into())
+            }
+            _ => known_holes.push(Some(*hole)),
+        }
+    }
+
+    let result = equity_vs_random(
+        &known_holes,
+        &parse_cards(board_ranks, board_suits),
+        &parse_cards(dead_ranks, dead_suits),
+        samples as u64,
+        seed as u64,
+    )?;
+    to_js(&result)
+}
+
+/// Number of canonical heads-up preflop matchups in a complete table
+/// 
+/// Each of the 169x169 starting-hand matchups appears once per suit relationship
+/// (e.g. AKs vs QQ with a shared suit and without), up to swapping the players.
+#[wasm_bindgen]
+pub fn preflop_matchup_count() -> u32 {
+    crate::preflop_matchup_count() as u32
+}
+
+/// Generate exact table entries for canonical matchups `start..end`
+/// 
+/// Every matchup enumerates all 1,712,304 boards, so the full table is an offline
+/// job (see generate-preflop-table.js); generating in chunks allows progress reporting.
+/// 
+/// Returns the raw 12-byte entries; pass their concatenation to `assemble_preflop_table`.
+#[wasm_bindgen]
+pub fn generate_preflop_table_chunk(start: u32, end: u32) -> Vec<u8> {
+    crate::generate_preflop_table_entries(start as usize, end as usize)
+}
+
+/// Build a preflop table file (header + sorted entries) from generated entries
+/// 
+/// Returns the table bytes; throws if the entries are truncated or inconsistent.
+#[wasm_bindgen]
+pub fn assemble_preflop_table(entries: &[u8]) -> Result<Vec<u8>, JsError> {
+    Ok(crate::assemble_preflop_table(entries)?)
+}
+
+/// Load a heads-up preflop table for `lookup_preflop_equity` and `calculate_equity`
+/// 
+/// Input: the bytes of a table file written by generate-preflop-table.js.
+/// 
+/// Returns a `PreflopTableInfo` object: {matchups:47008}; throws if the bytes are not a
+/// valid table.
+#[wasm_bindgen(unchecked_return_type = "PreflopTableInfo")]
+pub fn load_preflop_table(bytes: &[u8]) -> Result<JsValue, JsError> {
+    to_js(&crate::load_preflop_table(bytes)?)
+}
+
+/// Look up exact heads-up preflop equity in the loaded table
+/// 
+/// Input format:
+/// - player_ranks: ranks of both hands, hero first [r1, r2, r3, r4]
+/// - player_suits: suits of both hands [s1, s2, s3, s4]
+/// 
+/// Returns the same `EquityResult` as `calculate_equity` (samples is always 1712304);
+/// throws if no table is loaded, the cards are invalid, or the matchup is missing.
+#[wasm_bindgen(unchecked_return_type = "EquityResult")]
+pub fn lookup_preflop_equity(player_ranks: &[u8], player_suits: &[u8]) -> Result<JsValue, JsError> {
+    check_player_lengths(player_ranks, player_suits, 2)?;
+    let players = parse_players(player_ranks, player_suits, 2);
+    to_js(&crate::lookup_preflop_equity([players[0], players[1]])?)
+}
+
+/// Select the hand evaluator used by every equity calculation
+/// 
+/// Input: "direct" (rank counting, the default) or "table" (lookup tables, built on
+/// first selection). Both order every 7-card hand identically, so results are the same.
+/// 
+/// Returns an `EvaluatorBackendInfo` object: {backend:"table"}; throws for an unknown name.
+#[wasm_bindgen(unchecked_return_type = "EvaluatorBackendInfo")]
+pub fn set_evaluator_backend(name: &str) -> Result<JsValue, JsError> {
+    to_js(&crate::set_evaluator_backend(name)?)
+}
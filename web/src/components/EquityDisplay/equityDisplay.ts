@@ -76,7 +76,7 @@ export class EquityDisplay extends MobxLitElement {
         }
 
         // Check if player index is valid
-        const { win, tie } = result;
+        const { win, tie, tie_probability, chops } = result;
         if (this.playerIndex < 0 || this.playerIndex >= win.length) {
             return html`
                 <div class="equity-display empty">
@@ -87,9 +87,17 @@ export class EquityDisplay extends MobxLitElement {
 
         // Display equity for this specific player
         const winPercentage = (win[this.playerIndex] * 100).toFixed(2);
+        // `tie` is the pot share won by chopping; the chop chance is shown next to it
         const tiePercentage = (tie[this.playerIndex] * 100).toFixed(2);
+        const chopPercentage = tie_probability
+            ? (tie_probability[this.playerIndex] * 100).toFixed(2)
+            : null;
         // Only show tie if it's 0.01% or more
         const hasTie = parseFloat(tiePercentage) >= 0.01;
+        // 2-way, 3-way, ... chops this player can end up in
+        const chopWays = (chops?.[this.playerIndex] ?? [])
+            .map((probability, ways) => ({ ways, probability }))
+            .filter(({ probability }) => probability * 100 >= 0.01);
 
         return html`
             <div class="equity-display">
@@ -101,11 +109,35 @@ export class EquityDisplay extends MobxLitElement {
                     ${hasTie
                         ? html`
                               <div class="equity-stat tie">
-                                  <span class="stat-label">Tie:</span>
+                                  <span class="stat-label">Tie share:</span>
                                   <span class="stat-value"
                                       >${tiePercentage}%</span
                                   >
                               </div>
+                              ${chopPercentage !== null
+                                  ? html`
+                                        <div class="equity-stat chop">
+                                            <span class="stat-label"
+                                                >Chops:</span
+                                            >
+                                            <span class="stat-value"
+                                                >${chopPercentage}%</span
+                                            >
+                                        </div>
+                                        <div class="chop-breakdown">
+                                            ${chopWays.map(
+                                                ({ ways, probability }) => html`
+                                                    <span class="chop-ways"
+                                                        >${ways}-way:
+                                                        ${(
+                                                            probability * 100
+                                                        ).toFixed(2)}%</span
+                                                    >
+                                                `
+                                            )}
+                                        </div>
+                                    `
+                                  : ""}
                           `
                         : ""}
                 </div>
@@ -80,6 +80,18 @@ export const styles = css`
         font-weight: 600;
     }
 
+    .equity-stat.chop .stat-value {
+        color: var(--spectrum-global-color-orange-600);
+    }
+
+    .chop-breakdown {
+        display: flex;
+        flex-wrap: wrap;
+        gap: 0.5rem;
+        font-size: 0.75rem;
+        color: var(--spectrum-global-color-gray-600);
+    }
+
     .equity-stat.lose .stat-value {
         color: var(--spectrum-global-color-red-600);
         font-weight: 600;
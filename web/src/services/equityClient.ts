@@ -23,7 +23,9 @@ export interface EquityRequest {
 export interface EquityResponse {
     equity: {
         win: number[]; // Win percentages per player (0-1)
-        tie: number[]; // Tie percentages per player (0-1)
+        tie: number[]; // Pot share from ties per player (0-1)
+        tie_probability?: number[]; // Chance of splitting the pot per player (0-1)
+        chops?: number[][]; // Chance of an n-way split per player, indexed by n
         lose: number[]; // Lose percentages per player (0-1)
         samples: number;
     };